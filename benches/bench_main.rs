@@ -5,4 +5,8 @@ mod benchmarks;
 criterion_main! {
     benchmarks::bench_vec::vector_backed,
     benchmarks::bench_arr::array_backed,
+    benchmarks::bench_arr_uninit::uninit_array_backed,
+    benchmarks::bench_mirrored::mirrored_backed,
+    benchmarks::bench_rewind::rewind,
+    benchmarks::bench_sliding_iter::sliding_iter,
 }
@@ -0,0 +1,35 @@
+use criterion::{Criterion, criterion_group};
+
+use sliding_window::sliding_window::{new_with_uninit_array_storage, SlidingWindow};
+use sliding_window::storage_array_uninit::UninitArrayStorage;
+use crate::benchmarks::fields::{MULT, SIZE};
+
+
+#[derive(Default, Debug, Copy, Clone, Hash, Eq, PartialEq)]
+pub struct Data {
+    dats: i32,
+}
+
+fn get_sliding_window() -> SlidingWindow<UninitArrayStorage<Data, SIZE, MULT>, Data> {
+    new_with_uninit_array_storage()
+}
+
+fn uninit_array_backed_benchmark(criterion: &mut Criterion)
+{
+    let d1 = Data { dats: 0 };
+    let mut w = get_sliding_window();
+
+    criterion.bench_function("uninit_array_push", |bencher| {
+        bencher.iter(||
+            w.push(d1)
+        )
+    });
+}
+
+
+criterion_group! {
+    name = uninit_array_backed;
+    config = Criterion::default().sample_size(100);
+    targets =
+    uninit_array_backed_benchmark,
+}
@@ -0,0 +1,73 @@
+use criterion::{black_box, Criterion, criterion_group};
+
+use sliding_window::sliding_window::{new_with_array_storage, new_with_mirrored_storage, new_with_ring_storage, new_with_vector_storage, SlidingWindow};
+use sliding_window::storage::Storage;
+use sliding_window::storage_mirrored::MirroredStorage;
+use crate::benchmarks::fields::{MULT, SIZE};
+
+#[derive(Default, Debug, Copy, Clone, Hash, Eq, PartialEq)]
+pub struct Data {
+    dats: i32,
+}
+
+fn get_sliding_window() -> SlidingWindow<MirroredStorage<Data>, Data> {
+    new_with_mirrored_storage(SIZE)
+}
+
+fn mirrored_backed_benchmark(criterion: &mut Criterion)
+{
+    let d1 = Data { dats: 0 };
+    let mut w = get_sliding_window();
+
+    criterion.bench_function("mirrored_push", |bencher| {
+        bencher.iter(||
+            w.push(d1)
+        )
+    });
+}
+
+fn push_and_read<S>(mut w: SlidingWindow<S, i32>) -> impl FnMut() -> i32
+    where
+        S: Storage<i32>,
+{
+    let mut value = 0;
+    move || {
+        value += 1;
+        w.push(value);
+        w.iter().sum()
+    }
+}
+
+// Compares all backends that store Copy elements on a push followed by a read of the whole window.
+fn backend_comparison_benchmark(criterion: &mut Criterion)
+{
+    let mut group = criterion.benchmark_group("push_and_read");
+
+    let mut vector = push_and_read(new_with_vector_storage(SIZE, MULT));
+    group.bench_function("vector", |bencher| bencher.iter(|| black_box(vector())));
+
+    let mut array = push_and_read(new_with_array_storage::<i32, SIZE, MULT>());
+    group.bench_function("array", |bencher| bencher.iter(|| black_box(array())));
+
+    let mut ring = push_and_read(new_with_ring_storage(SIZE));
+    group.bench_function("ring", |bencher| bencher.iter(|| black_box(ring())));
+
+    let mut mirrored = push_and_read(new_with_mirrored_storage(SIZE));
+    group.bench_function("mirrored", |bencher| bencher.iter(|| black_box(mirrored())));
+
+    #[cfg(all(feature = "mmap", target_os = "linux"))]
+    {
+        let mut mmap = push_and_read(sliding_window::sliding_window::new_with_mmap_storage(SIZE));
+        group.bench_function("mmap", |bencher| bencher.iter(|| black_box(mmap())));
+    }
+
+    group.finish();
+}
+
+criterion_group! {
+    name = mirrored_backed;
+    config = Criterion::default().sample_size(100);
+    targets =
+    mirrored_backed_benchmark,
+    backend_comparison_benchmark,
+}
@@ -0,0 +1,39 @@
+use criterion::{Criterion, criterion_group};
+
+use sliding_window::rewind::Rewind;
+use sliding_window::sliding_window::{new_with_array_storage_and_rewind, new_with_vector_storage_and_rewind};
+
+// A large window, for which the copy of a full rewind is noticeable.
+const SIZE: usize = 1000;
+const MULT: usize = 10;
+const CAPACITY: usize = SIZE * MULT;
+
+fn rewind_benchmark(criterion: &mut Criterion)
+{
+    let mut group = criterion.benchmark_group("rewind");
+
+    for rewind in [Rewind::Full, Rewind::Incremental] {
+        let mut w = new_with_vector_storage_and_rewind(SIZE, MULT, rewind);
+        group.bench_function(format!("vector_push_{:?}", rewind), |bencher| {
+            bencher.iter(||
+                w.push(1)
+            )
+        });
+
+        let mut w = new_with_array_storage_and_rewind::<i32, SIZE, CAPACITY>(rewind);
+        group.bench_function(format!("array_push_{:?}", rewind), |bencher| {
+            bencher.iter(||
+                w.push(1)
+            )
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group! {
+    name = rewind;
+    config = Criterion::default().sample_size(100);
+    targets =
+    rewind_benchmark,
+}
@@ -0,0 +1,35 @@
+use criterion::{black_box, Criterion, criterion_group};
+
+use sliding_window::sliding_iter::SlidingIteratorExt;
+use crate::benchmarks::fields::{MULT, SIZE};
+
+const ITEMS: usize = 10_000;
+
+fn sliding_iter_benchmark(criterion: &mut Criterion)
+{
+    criterion.bench_function("sliding_iter_vector_next_window", |bencher| {
+        bencher.iter(|| {
+            let mut sliding = (0..ITEMS).sliding(SIZE, MULT);
+            while let Some(window) = sliding.next_window() {
+                black_box(window);
+            }
+        })
+    });
+
+    criterion.bench_function("sliding_iter_array_next_window", |bencher| {
+        bencher.iter(|| {
+            let mut sliding = (0..ITEMS).sliding_array::<SIZE, MULT>();
+            while let Some(window) = sliding.next_window() {
+                black_box(window);
+            }
+        })
+    });
+}
+
+
+criterion_group! {
+    name = sliding_iter;
+    config = Criterion::default().sample_size(100);
+    targets =
+    sliding_iter_benchmark,
+}
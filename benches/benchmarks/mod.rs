@@ -1,3 +1,7 @@
 pub mod bench_arr;
+pub mod bench_arr_uninit;
+pub mod bench_mirrored;
+pub mod bench_rewind;
+pub mod bench_sliding_iter;
 pub mod bench_vec;
 mod fields;
\ No newline at end of file
@@ -1,3 +1,5 @@
+#![allow(clippy::bool_assert_comparison)]
+
 use sliding_window::sliding_window::{new_with_vector_storage, SlidingWindow};
 use sliding_window::storage_vec::VectorStorage;
 
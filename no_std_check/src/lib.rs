@@ -0,0 +1,27 @@
+//! Proves that the array backed sliding window builds without `std`.
+//! Defining a panic handler fails with a duplicate lang item error
+//! as soon as any dependency links `std`.
+#![no_std]
+
+use core::panic::PanicInfo;
+
+use sliding_window::rolling_stats::RollingStats;
+use sliding_window::sliding_window::{new_with_array_storage, SlidingWindow};
+use sliding_window::storage_array::ArrayStorage;
+
+/// A window can be constructed at compile time and live in a static.
+pub static WINDOW: SlidingWindow<ArrayStorage<u32, 4, 16>, u32> = new_with_array_storage();
+
+/// Returns the mean of the last four samples.
+pub fn mean_of_last_four(samples: &[u32]) -> f64 {
+    let mut stats = RollingStats::new(new_with_array_storage::<u32, 4, 16>());
+    for &sample in samples {
+        stats.push(sample);
+    }
+    stats.mean().unwrap_or(0.0)
+}
+
+#[panic_handler]
+fn panic(_info: &PanicInfo) -> ! {
+    loop {}
+}
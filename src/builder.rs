@@ -0,0 +1,132 @@
+/*
+ * Copyright (c) 2023. Marvin Hansen <marvin.hansen@gmail.com> All rights reserved.
+ */
+
+use crate::error::SlidingWindowError;
+use crate::rewind::Rewind;
+use crate::sliding_window::{AssertCapacity, SlidingWindow};
+use crate::storage_array::ArrayStorage;
+#[cfg(feature = "alloc")]
+use crate::storage_selected::SelectedStorage;
+#[cfg(feature = "alloc")]
+use crate::storage_vec::VectorStorage;
+use crate::warm_up::WarmUp;
+
+/// Default multiple of the window size that a vector backed window allocates.
+pub const DEFAULT_MULTIPLE: usize = 100;
+
+/// Builder that validates the configuration of a sliding window before it is created.
+///
+/// `build` creates a vector backed window from the runtime size and multiple, and returns
+/// an error instead of panicking, or rewinding on every push, for a bad configuration.
+/// `build_array` creates an array backed window from const generics, whose capacity
+/// is checked at compile time. `build_selected` chooses between both from the configuration.
+#[derive(Debug, Clone)]
+pub struct SlidingWindowBuilder<T>
+{
+    size: Option<usize>,
+    multiple: usize,
+    warm_up: WarmUp<T>,
+    rewind: Rewind,
+}
+
+impl<T> Default for SlidingWindowBuilder<T>
+{
+    fn default() -> Self
+    {
+        Self::new()
+    }
+}
+
+impl<T> SlidingWindowBuilder<T>
+{
+    pub fn new() -> Self
+    {
+        Self { size: None, multiple: DEFAULT_MULTIPLE, warm_up: WarmUp::Strict, rewind: Rewind::Full }
+    }
+
+    /// Sets the window size. Required for `build`.
+    pub fn size(mut self, size: usize) -> Self
+    {
+        self.size = Some(size);
+        self
+    }
+
+    /// Sets the multiple of the window size that a vector backed window allocates.
+    /// Defaults to `DEFAULT_MULTIPLE`.
+    pub fn multiple(mut self, multiple: usize) -> Self
+    {
+        self.multiple = multiple;
+        self
+    }
+
+    /// Sets the warm-up policy. Defaults to `WarmUp::Strict`.
+    pub fn warm_up(mut self, warm_up: WarmUp<T>) -> Self
+    {
+        self.warm_up = warm_up;
+        self
+    }
+
+    /// Sets how the window rewinds once its capacity is exhausted. Defaults to `Rewind::Full`.
+    pub fn rewind(mut self, rewind: Rewind) -> Self
+    {
+        self.rewind = rewind;
+        self
+    }
+}
+
+impl<T> SlidingWindowBuilder<T>
+    where
+        T: PartialEq + Copy,
+{
+    /// Validates the configuration and creates a vector backed window.
+    ///
+    /// Fails with `InvalidSize` if the size is missing or zero,
+    /// with `InvalidMultiple` if the multiple is less than two
+    /// or the capacity `size * multiple` overflows, in elements or in bytes,
+    /// and with `AllocationFailed` if the capacity cannot be allocated.
+    #[cfg(feature = "alloc")]
+    pub fn build(self) -> Result<SlidingWindow<VectorStorage<T>, T>, SlidingWindowError>
+    {
+        let size = self.size.ok_or(SlidingWindowError::InvalidSize)?;
+        let storage = VectorStorage::try_with_rewind(size, self.multiple, self.rewind)?;
+        Ok(SlidingWindow::with_storage_and_warm_up(storage, self.warm_up))
+    }
+
+    /// Creates an array backed window of `SIZE` elements over `CAPACITY` elements.
+    ///
+    /// Fails with `SizeMismatch` if a size was set that differs from `SIZE`.
+    /// The multiple is ignored. A `CAPACITY` that is not greater than `SIZE` fails to compile:
+    ///
+    /// ```compile_fail
+    /// use sliding_window::builder::SlidingWindowBuilder;
+    ///
+    /// let window = SlidingWindowBuilder::<i32>::new().build_array::<8, 8>();
+    /// ```
+    pub fn build_array<const SIZE: usize, const CAPACITY: usize>(self) -> Result<SlidingWindow<ArrayStorage<T, SIZE, CAPACITY>, T>, SlidingWindowError>
+    {
+        assert!(AssertCapacity::<SIZE, CAPACITY>::OK);
+
+        match self.size {
+            Some(size) if size != SIZE => Err(SlidingWindowError::SizeMismatch { expected: SIZE, got: size }),
+            _ => Ok(SlidingWindow::with_storage_and_warm_up(ArrayStorage::with_rewind(self.rewind), self.warm_up)),
+        }
+    }
+
+    /// Creates a window whose backend is selected from the configuration.
+    ///
+    /// If the size is missing or equals `SIZE`, the window fits the const generic capacity
+    /// and is backed by an array of `CAPACITY` elements, like `build_array`, which ignores the multiple.
+    /// Otherwise, it is backed by a vector of `size * multiple` elements, like `build`, and fails the same way.
+    #[cfg(feature = "alloc")]
+    pub fn build_selected<const SIZE: usize, const CAPACITY: usize>(self) -> Result<SlidingWindow<SelectedStorage<T, SIZE, CAPACITY>, T>, SlidingWindowError>
+    {
+        assert!(AssertCapacity::<SIZE, CAPACITY>::OK);
+
+        let storage = match self.size {
+            Some(size) if size != SIZE => SelectedStorage::Vector(VectorStorage::try_with_rewind(size, self.multiple, self.rewind)?),
+            _ => SelectedStorage::Array(ArrayStorage::with_rewind(self.rewind)),
+        };
+        Ok(SlidingWindow::with_storage_and_warm_up(storage, self.warm_up))
+    }
+}
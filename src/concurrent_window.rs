@@ -0,0 +1,320 @@
+/*
+ * Copyright (c) 2023. Marvin Hansen <marvin.hansen@gmail.com> All rights reserved.
+ */
+
+//! Single producer, multiple consumer sliding window.
+//!
+//! One writer pushes into an over-allocated buffer, like the array backed window,
+//! while any number of readers copy the current window without blocking the writer.
+//! Consistency is guaranteed by a sequence lock: the writer makes the sequence odd
+//! before it touches the buffer or the cursors and even again afterwards, and a reader
+//! retries whenever the sequence was odd or changed while it copied the window.
+//! This covers rewinds as well, so readers never observe torn or half-rewound data.
+//!
+//! All shared state, including the elements, lives in atomics, which keeps
+//! the optimistic reads free of data races.
+
+use core::marker::PhantomData;
+
+#[cfg(not(loom))]
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+#[cfg(not(loom))]
+use core::hint::spin_loop;
+#[cfg(not(loom))]
+use core::sync::atomic::{fence, AtomicU64, AtomicUsize, Ordering};
+#[cfg(loom)]
+use loom::hint::spin_loop;
+#[cfg(loom)]
+use loom::sync::atomic::{fence, AtomicU64, AtomicUsize, Ordering};
+#[cfg(loom)]
+use loom::sync::Arc;
+
+use crate::error::SlidingWindowError;
+use crate::sliding_window::AssertCapacity;
+
+/// Element types that can be stored in a concurrent window.
+///
+/// Elements are stored as the bits of a `u64`, so that every slot of the buffer is an atomic.
+/// `from_bits` must accept any value returned by `to_bits`.
+pub trait AtomicElement: Copy {
+    /// Converts the value into its bit representation.
+    fn to_bits(self) -> u64;
+    /// Converts a bit representation back into the value.
+    fn from_bits(bits: u64) -> Self;
+}
+
+macro_rules! impl_atomic_element_int {
+    ($($t:ty),*) => {
+        $(
+            impl AtomicElement for $t {
+                #[inline(always)]
+                fn to_bits(self) -> u64 {
+                    self as u64
+                }
+
+                #[inline(always)]
+                fn from_bits(bits: u64) -> Self {
+                    bits as $t
+                }
+            }
+        )*
+    };
+}
+
+impl_atomic_element_int!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+impl AtomicElement for f32 {
+    #[inline(always)]
+    fn to_bits(self) -> u64 {
+        f32::to_bits(self) as u64
+    }
+
+    #[inline(always)]
+    fn from_bits(bits: u64) -> Self {
+        f32::from_bits(bits as u32)
+    }
+}
+
+impl AtomicElement for f64 {
+    #[inline(always)]
+    fn to_bits(self) -> u64 {
+        f64::to_bits(self)
+    }
+
+    #[inline(always)]
+    fn from_bits(bits: u64) -> Self {
+        f64::from_bits(bits)
+    }
+}
+
+struct Shared<T, const SIZE: usize, const CAPACITY: usize> {
+    seq: AtomicUsize,
+    head: AtomicUsize,
+    tail: AtomicUsize,
+    arr: [AtomicU64; CAPACITY],
+    ty: PhantomData<T>,
+}
+
+/// Creates a concurrent window of `SIZE` elements over a buffer of `CAPACITY` elements
+/// and returns its single writer together with a reader. Readers can be cloned freely.
+pub fn new_concurrent_window<T, const SIZE: usize, const CAPACITY: usize>(
+)
+    -> (ConcurrentWindowWriter<T, SIZE, CAPACITY>, ConcurrentWindowReader<T, SIZE, CAPACITY>)
+    where
+        T: AtomicElement,
+{
+    assert!(AssertCapacity::<SIZE, CAPACITY>::OK);
+
+    let shared = Arc::new(Shared {
+        seq: AtomicUsize::new(0),
+        head: AtomicUsize::new(0),
+        tail: AtomicUsize::new(0),
+        arr: core::array::from_fn(|_| AtomicU64::new(0)),
+        ty: PhantomData,
+    });
+
+    let writer = ConcurrentWindowWriter { shared: shared.clone(), seq: 0, head: 0, tail: 0 };
+    let reader = ConcurrentWindowReader { shared };
+    (writer, reader)
+}
+
+/// The single writer of a concurrent window.
+///
+/// The writer keeps its own copy of the sequence and the cursors,
+/// so pushing never reads shared state written by another thread.
+pub struct ConcurrentWindowWriter<T, const SIZE: usize, const CAPACITY: usize>
+    where
+        T: AtomicElement,
+{
+    shared: Arc<Shared<T, SIZE, CAPACITY>>,
+    seq: usize,
+    head: usize,
+    tail: usize,
+}
+
+#[allow(clippy::len_without_is_empty)]
+impl<T, const SIZE: usize, const CAPACITY: usize> ConcurrentWindowWriter<T, SIZE, CAPACITY>
+    where
+        T: AtomicElement,
+{
+    /// Pushes a new element to the beginning of the sliding window.
+    /// If the window is filled, the last element will be dropped.
+    pub fn push(&mut self, value: T)
+    {
+        let shared = &*self.shared;
+
+        // Mark the window as being written. The fence orders this store
+        // before the element and cursor stores below.
+        shared.seq.store(self.seq.wrapping_add(1), Ordering::Relaxed);
+        fence(Ordering::Release);
+
+        // if the array is full, rewind the newest size - 1 elements to the front
+        if self.tail == CAPACITY
+        {
+            let start = self.tail + 1 - SIZE;
+            for i in 0..SIZE - 1 {
+                let bits = shared.arr[start + i].load(Ordering::Relaxed);
+                shared.arr[i].store(bits, Ordering::Relaxed);
+            }
+            self.head = 0;
+            self.tail = SIZE - 1;
+        }
+
+        shared.arr[self.tail].store(value.to_bits(), Ordering::Relaxed);
+        self.tail += 1;
+        if self.tail - self.head > SIZE
+        {
+            self.head += 1;
+        }
+
+        shared.head.store(self.head, Ordering::Relaxed);
+        shared.tail.store(self.tail, Ordering::Relaxed);
+
+        // Publish the new window.
+        self.seq = self.seq.wrapping_add(2);
+        shared.seq.store(self.seq, Ordering::Release);
+    }
+
+    /// Returns the number of elements in the window.
+    pub fn len(&self) -> usize
+    {
+        self.tail - self.head
+    }
+
+    /// Returns true if the window is empty.
+    pub fn empty(&self) -> bool
+    {
+        self.tail == 0
+    }
+
+    /// Returns true if the window is filled.
+    pub fn filled(&self) -> bool
+    {
+        self.tail >= SIZE
+    }
+
+    /// Returns a new reader of the window.
+    pub fn reader(&self) -> ConcurrentWindowReader<T, SIZE, CAPACITY>
+    {
+        ConcurrentWindowReader { shared: self.shared.clone() }
+    }
+}
+
+/// A reader of a concurrent window.
+///
+/// Every read returns data from a single consistent state of the window.
+/// Reads never block the writer, but retry while the writer pushes.
+pub struct ConcurrentWindowReader<T, const SIZE: usize, const CAPACITY: usize>
+    where
+        T: AtomicElement,
+{
+    shared: Arc<Shared<T, SIZE, CAPACITY>>,
+}
+
+impl<T, const SIZE: usize, const CAPACITY: usize> Clone for ConcurrentWindowReader<T, SIZE, CAPACITY>
+    where
+        T: AtomicElement,
+{
+    fn clone(&self) -> Self
+    {
+        Self { shared: self.shared.clone() }
+    }
+}
+
+#[allow(clippy::len_without_is_empty)]
+impl<T, const SIZE: usize, const CAPACITY: usize> ConcurrentWindowReader<T, SIZE, CAPACITY>
+    where
+        T: AtomicElement,
+{
+    /// Runs `read` on the elements of the window until it ran on a consistent state.
+    fn read<R>(&self, mut read: impl FnMut(&[AtomicU64]) -> R) -> R
+    {
+        let shared = &*self.shared;
+        loop {
+            let seq = shared.seq.load(Ordering::Acquire);
+            if seq & 1 == 0 {
+                let head = shared.head.load(Ordering::Relaxed);
+                let tail = shared.tail.load(Ordering::Relaxed);
+
+                // Cursors from different pushes are rejected by the sequence check,
+                // but must not cause an out of bounds access before.
+                if head <= tail && tail <= CAPACITY {
+                    let result = read(&shared.arr[head..tail]);
+
+                    // The fence orders the loads above before the sequence check.
+                    fence(Ordering::Acquire);
+                    if shared.seq.load(Ordering::Relaxed) == seq {
+                        return result;
+                    }
+                }
+            }
+            spin_loop();
+        }
+    }
+
+    /// Returns the number of elements in the window.
+    pub fn len(&self) -> usize
+    {
+        self.read(|arr| arr.len())
+    }
+
+    /// Returns true if the window is empty.
+    pub fn empty(&self) -> bool
+    {
+        self.len() == 0
+    }
+
+    /// Returns true if the window is filled.
+    pub fn filled(&self) -> bool
+    {
+        self.len() == SIZE
+    }
+
+    pub fn size(&self) -> usize
+    {
+        SIZE
+    }
+
+    /// Returns the first element in the sliding window
+    pub fn first(&self) -> Result<T, SlidingWindowError>
+    {
+        self.read(|arr| match arr.first() {
+            Some(bits) => Ok(T::from_bits(bits.load(Ordering::Relaxed))),
+            None => Err(SlidingWindowError::Empty),
+        })
+    }
+
+    /// Returns the last element in the sliding window
+    pub fn last(&self) -> Result<T, SlidingWindowError>
+    {
+        self.read(|arr| match arr.last() {
+            Some(bits) if arr.len() == SIZE => Ok(T::from_bits(bits.load(Ordering::Relaxed))),
+            _ => Err(SlidingWindowError::NotFilled { len: arr.len(), size: SIZE }),
+        })
+    }
+
+    /// Returns a consistent copy of the sliding window as a fixed size static array.
+    pub fn arr(&self) -> Result<[T; SIZE], SlidingWindowError>
+    {
+        self.read(|arr| {
+            if arr.len() == SIZE {
+                Ok(core::array::from_fn(|i| T::from_bits(arr[i].load(Ordering::Relaxed))))
+            } else {
+                Err(SlidingWindowError::NotFilled { len: arr.len(), size: SIZE })
+            }
+        })
+    }
+
+    /// Returns a consistent copy of the sliding window as a vector.
+    pub fn vec(&self) -> Result<Vec<T>, SlidingWindowError>
+    {
+        self.read(|arr| {
+            if arr.len() == SIZE {
+                Ok(arr.iter().map(|bits| T::from_bits(bits.load(Ordering::Relaxed))).collect())
+            } else {
+                Err(SlidingWindowError::NotFilled { len: arr.len(), size: SIZE })
+            }
+        })
+    }
+}
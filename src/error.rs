@@ -0,0 +1,67 @@
+/*
+ * Copyright (c) 2023. Marvin Hansen <marvin.hansen@gmail.com> All rights reserved.
+ */
+
+use core::fmt;
+
+/// Errors returned by the fallible accessors of a sliding window.
+///
+/// All variants are plain data so that returning an error never allocates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SlidingWindowError {
+    /// The window does not hold any element yet.
+    Empty,
+    /// The window holds `len` elements, but `size` are required.
+    NotFilled { len: usize, size: usize },
+    /// The requested output has `got` elements, but the window size is `expected`.
+    SizeMismatch { expected: usize, got: usize },
+    /// The requested `index` is out of bounds for a window holding `len` elements.
+    OutOfBounds { index: usize, len: usize },
+    /// The requested quantile is not within `[0, 1]`.
+    InvalidQuantile,
+    /// The window size is zero.
+    InvalidSize,
+    /// The `multiple` is less than two, or the resulting capacity overflows, in elements or in bytes.
+    InvalidMultiple { multiple: usize },
+    /// The window wraps around the end of a ring buffer, so it is not a single slice.
+    NotContiguous,
+    /// The buffer of `capacity` elements could not be allocated.
+    AllocationFailed { capacity: usize },
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SlidingWindowError {}
+
+impl fmt::Display for SlidingWindowError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SlidingWindowError::Empty => {
+                write!(f, "Sliding window is empty. Add some elements to the window first")
+            }
+            SlidingWindowError::NotFilled { len, size } => {
+                write!(f, "Sliding window is not yet filled: {} of {} elements", len, size)
+            }
+            SlidingWindowError::SizeMismatch { expected, got } => {
+                write!(f, "Size mismatch: window size is {}, but {} was requested", expected, got)
+            }
+            SlidingWindowError::OutOfBounds { index, len } => {
+                write!(f, "Index out of bounds: the index is {}, but the window holds {} elements", index, len)
+            }
+            SlidingWindowError::InvalidQuantile => {
+                write!(f, "Invalid quantile. The quantile must be within [0, 1]")
+            }
+            SlidingWindowError::InvalidSize => {
+                write!(f, "Invalid size. The window size must be greater than zero")
+            }
+            SlidingWindowError::InvalidMultiple { multiple } => {
+                write!(f, "Invalid multiple: {}. The multiple must be at least 2, and size times multiple must not overflow", multiple)
+            }
+            SlidingWindowError::NotContiguous => {
+                write!(f, "Sliding window is not contiguous. Call make_contiguous first")
+            }
+            SlidingWindowError::AllocationFailed { capacity } => {
+                write!(f, "Allocation failed: the buffer of {} elements could not be allocated", capacity)
+            }
+        }
+    }
+}
@@ -0,0 +1,233 @@
+/*
+ * Copyright (c) 2023. Marvin Hansen <marvin.hansen@gmail.com> All rights reserved.
+ */
+
+use alloc::vec::{Drain, Vec};
+use core::time::Duration;
+
+use crate::error::SlidingWindowError;
+
+/// What happens to an element whose timestamp is older than the watermark.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LatePolicy {
+    /// Late elements are discarded.
+    #[default]
+    Drop,
+    /// Late elements are collected separately, see `late` and `drain_late`.
+    SideOutput,
+    /// Late elements are inserted into the window, as long as they are within its duration.
+    Update,
+}
+
+/// Outcome of pushing an element into an event-time window.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Arrival {
+    /// The element was not older than the watermark and was inserted in timestamp order.
+    OnTime,
+    /// The element was late and was inserted into the window under `LatePolicy::Update`.
+    Updated,
+    /// The element was late and was collected under `LatePolicy::SideOutput`.
+    SideOutput,
+    /// The element was late and was discarded.
+    Dropped,
+}
+
+/// Sliding window over the last `duration` of event time, for elements that arrive out of order.
+///
+/// Elements are kept sorted by timestamp. The watermark trails the newest timestamp
+/// by the allowed lateness, i.e. elements may arrive up to `allowed_lateness` out of order.
+/// Elements older than the watermark are late and handled by the `LatePolicy`.
+/// Each push evicts all elements older than `duration` relative to the watermark.
+///
+/// Like the time based window, elements are stored in a pre-allocated vector.
+/// In-order elements are appended, out-of-order elements are inserted close to the end,
+/// and evicted elements are only discarded once the vector is full,
+/// at which point the live elements are rewound to the front of the vector.
+pub struct EventTimeWindow<T>
+    where
+        T: PartialEq + Copy,
+{
+    vec: Vec<(Duration, T)>,
+    late: Vec<(Duration, T)>,
+    duration: Duration,
+    allowed_lateness: Duration,
+    policy: LatePolicy,
+    watermark: Duration,
+    head: usize,
+}
+
+#[allow(clippy::len_without_is_empty)]
+impl<T> EventTimeWindow<T>
+    where
+        T: PartialEq + Copy,
+{
+    /// Creates a new event-time window over `duration` that accepts elements up to
+    /// `allowed_lateness` out of order and pre-allocates `capacity` elements.
+    pub fn new(duration: Duration, allowed_lateness: Duration, policy: LatePolicy, capacity: usize) -> Self
+    {
+        Self {
+            vec: Vec::with_capacity(capacity),
+            late: Vec::new(),
+            duration,
+            allowed_lateness,
+            policy,
+            watermark: Duration::ZERO,
+            head: 0,
+        }
+    }
+
+    /// Pushes a new element with its event timestamp and advances the watermark.
+    pub fn push_at(&mut self, timestamp: Duration, value: T) -> Arrival {
+        if timestamp < self.watermark {
+            return self.push_late(timestamp, value);
+        }
+
+        self.insert(timestamp, value);
+        self.advance_watermark(timestamp.saturating_sub(self.allowed_lateness));
+        Arrival::OnTime
+    }
+
+    fn push_late(&mut self, timestamp: Duration, value: T) -> Arrival {
+        match self.policy {
+            LatePolicy::Drop => Arrival::Dropped,
+            LatePolicy::SideOutput => {
+                self.late.push((timestamp, value));
+                Arrival::SideOutput
+            }
+            // an end beyond the maximum duration never passes the watermark
+            LatePolicy::Update if timestamp.checked_add(self.duration).map_or(true, |end| end > self.watermark) => {
+                self.insert(timestamp, value);
+                Arrival::Updated
+            }
+            LatePolicy::Update => Arrival::Dropped,
+        }
+    }
+
+    fn insert(&mut self, timestamp: Duration, value: T) {
+        // if the vector is full, rewind
+        if self.head > 0 && self.vec.len() == self.vec.capacity()
+        {
+            let len = self.vec.len();
+            self.vec.copy_within(self.head..len, 0);
+            self.vec.truncate(len - self.head);
+            self.head = 0;
+
+            // keep at least half of the vector spare, so that a rewind only happens
+            // after as many pushes as it copies elements
+            let len = self.vec.len();
+            if len > self.vec.capacity() / 2
+            {
+                self.vec.reserve(len);
+            }
+        }
+
+        match self.vec.last() {
+            Some(&(last, _)) if timestamp < last => {
+                // Elements with equal timestamps keep their arrival order.
+                let index = self.head + self.get_slice().partition_point(|&(t, _)| t <= timestamp);
+                self.vec.insert(index, (timestamp, value));
+            }
+            _ => self.vec.push((timestamp, value)),
+        }
+    }
+
+    /// Advances the watermark to `watermark`, i.e. for an idle source, and evicts
+    /// all elements that are older than `duration` relative to it.
+    /// The watermark never moves backwards.
+    pub fn advance_watermark(&mut self, watermark: Duration) {
+        if watermark <= self.watermark {
+            return;
+        }
+
+        self.watermark = watermark;
+        while self.head < self.vec.len()
+            && self.vec[self.head].0.checked_add(self.duration).map_or(false, |end| end <= watermark)
+        {
+            self.head += 1;
+        }
+    }
+
+    /// Returns the watermark. Elements older than the watermark are late.
+    pub fn watermark(&self) -> Duration {
+        self.watermark
+    }
+
+    /// Returns the first (oldest) element together with its timestamp.
+    pub fn first_entry(&self) -> Result<(Duration, T), SlidingWindowError> {
+        self.get_slice().first().copied().ok_or(SlidingWindowError::Empty)
+    }
+
+    /// Returns the last (newest) element together with its timestamp.
+    pub fn last_entry(&self) -> Result<(Duration, T), SlidingWindowError> {
+        self.get_slice().last().copied().ok_or(SlidingWindowError::Empty)
+    }
+
+    /// Returns the window as slice of `(timestamp, value)` pairs in timestamp order, which may be empty.
+    #[inline(always)]
+    pub fn get_slice(&self) -> &[(Duration, T)] {
+        &self.vec[self.head..]
+    }
+
+    /// Returns the elements older than the watermark, which can only change
+    /// through late elements under `LatePolicy::Update`.
+    pub fn complete(&self) -> &[(Duration, T)] {
+        let slice = self.get_slice();
+        &slice[..slice.partition_point(|&(t, _)| t < self.watermark)]
+    }
+
+    /// Returns the elements not older than the watermark,
+    /// between which out-of-order elements may still be inserted.
+    pub fn pending(&self) -> &[(Duration, T)] {
+        let slice = self.get_slice();
+        &slice[slice.partition_point(|&(t, _)| t < self.watermark)..]
+    }
+
+    /// Returns the values of the window in timestamp order as vector.
+    pub fn vec(&self) -> Result<Vec<T>, SlidingWindowError> {
+        if self.empty() {
+            Err(SlidingWindowError::Empty)
+        } else {
+            Ok(self.get_slice().iter().map(|(_, v)| *v).collect())
+        }
+    }
+
+    /// Returns the late elements collected under `LatePolicy::SideOutput`, in arrival order.
+    pub fn late(&self) -> &[(Duration, T)] {
+        &self.late
+    }
+
+    /// Removes and returns the late elements collected under `LatePolicy::SideOutput`.
+    pub fn drain_late(&mut self) -> Drain<'_, (Duration, T)> {
+        self.late.drain(..)
+    }
+
+    /// Returns the number of elements in the window.
+    pub fn len(&self) -> usize {
+        self.vec.len() - self.head
+    }
+
+    /// Returns true if the window is empty.
+    pub fn empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the number of elements the vector can hold before the next rewind.
+    pub fn capacity(&self) -> usize {
+        self.vec.capacity()
+    }
+
+    /// Returns the time span covered by the window.
+    pub fn duration(&self) -> Duration {
+        self.duration
+    }
+
+    /// Returns how far elements may arrive out of order before they are late.
+    pub fn allowed_lateness(&self) -> Duration {
+        self.allowed_lateness
+    }
+
+    /// Returns the policy for late elements.
+    pub fn policy(&self) -> LatePolicy {
+        self.policy
+    }
+}
@@ -0,0 +1,93 @@
+/*
+ * Copyright (c) 2023. Marvin Hansen <marvin.hansen@gmail.com> All rights reserved.
+ */
+
+use crate::sliding_window::SlidingWindow;
+use crate::storage::Storage;
+use crate::warm_up::WarmUp;
+
+/// Sliding window that reports a new window only every `step` pushes.
+///
+/// A hopping window of size 50 and step 10 reports the last 50 elements every 10 elements.
+/// A tumbling window is a hopping window whose step equals its size,
+/// so that the reported windows do not overlap.
+/// The first window is reported as soon as the sliding window can be read,
+/// i.e. once it is filled, or on the first push if the warm-up policy allows earlier reads.
+pub struct HoppingWindow<S, T>
+    where
+        S: Storage<T>,
+{
+    window: SlidingWindow<S, T>,
+    step: usize,
+    countdown: usize,
+}
+
+impl<S, T> HoppingWindow<S, T>
+    where
+        S: Storage<T>,
+{
+    /// Creates a hopping window that reports the given window every `step` pushes.
+    /// Panics if `step` is zero.
+    pub fn new(window: SlidingWindow<S, T>, step: usize) -> Self
+    {
+        assert!(step > 0);
+
+        Self { window, step, countdown: 0 }
+    }
+
+    /// Creates a tumbling window that reports non-overlapping windows,
+    /// i.e. a hopping window whose step equals the window size.
+    pub fn tumbling(window: SlidingWindow<S, T>) -> Self
+    {
+        let step = window.size();
+        Self::new(window, step)
+    }
+
+    /// Pushes a new element and returns the window if a new window is ready.
+    /// A window that wraps around, such as a ring buffer, is made contiguous first.
+    pub fn push(&mut self, value: T) -> Option<&[T]>
+    {
+        self.window.push(value);
+        self.countdown = self.countdown.saturating_sub(1);
+
+        if self.countdown > 0 || !(self.window.filled() || matches!(self.window.warm_up(), WarmUp::Partial)) {
+            return None;
+        }
+
+        self.countdown = self.step;
+        Some(self.window.make_contiguous())
+    }
+
+    /// Pushes a new element and calls `f` with the window if a new window is ready.
+    /// Returns true if `f` was called.
+    pub fn push_with<F>(&mut self, value: T, f: F) -> bool
+        where
+            F: FnOnce(&[T]),
+    {
+        match self.push(value) {
+            Some(window) => {
+                f(window);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Returns the number of pushes between two reported windows.
+    pub fn step(&self) -> usize
+    {
+        self.step
+    }
+
+    /// Returns the underlying sliding window.
+    pub fn window(&self) -> &SlidingWindow<S, T>
+    {
+        &self.window
+    }
+
+    /// Consumes the hopping window and returns the underlying sliding window.
+    pub fn into_inner(self) -> SlidingWindow<S, T>
+    {
+        self.window
+    }
+}
@@ -0,0 +1,76 @@
+/*
+ * Copyright (c) 2023. Marvin Hansen <marvin.hansen@gmail.com> All rights reserved.
+ */
+
+use core::iter::FusedIterator;
+use core::slice;
+
+/// Iterator over the elements of a sliding window, from the first (oldest) to the last (newest).
+///
+/// A window is made of up to two slices, see `Storage::as_slices`.
+#[derive(Debug, Clone)]
+pub struct Iter<'a, T> {
+    front: slice::Iter<'a, T>,
+    back: slice::Iter<'a, T>,
+}
+
+impl<'a, T> Iter<'a, T> {
+    pub(crate) fn new(front: &'a [T], back: &'a [T]) -> Self
+    {
+        Self { front: front.iter(), back: back.iter() }
+    }
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.front.next() {
+            None => self.back.next(),
+            item => item,
+        }
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.front.len() + self.back.len();
+        (len, Some(len))
+    }
+
+    #[inline(always)]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let front = self.front.len();
+        if n < front {
+            self.front.nth(n)
+        } else {
+            self.front = [].iter();
+            self.back.nth(n - front)
+        }
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Iter<'a, T> {
+    #[inline(always)]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        match self.back.next_back() {
+            None => self.front.next_back(),
+            item => item,
+        }
+    }
+
+    #[inline(always)]
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        let back = self.back.len();
+        if n < back {
+            self.back.nth_back(n)
+        } else {
+            self.back = [].iter();
+            self.front.nth_back(n - back)
+        }
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Iter<'a, T> {}
+
+impl<'a, T> FusedIterator for Iter<'a, T> {}
@@ -0,0 +1,258 @@
+/*
+ * Copyright (c) 2023. Marvin Hansen <marvin.hansen@gmail.com> All rights reserved.
+ */
+
+use alloc::vec::Vec;
+use core::hash::Hash;
+use core::mem::MaybeUninit;
+use core::slice;
+use core::time::Duration;
+use std::collections::HashMap;
+
+use crate::error::SlidingWindowError;
+use crate::storage_vec::checked_capacity;
+use crate::time_window::{Clock, SystemClock};
+
+/// Cursors of the window of one key within the pool.
+#[derive(Debug, Clone, Copy)]
+struct Slot {
+    head: usize,
+    tail: usize,
+    last_push: Duration,
+}
+
+/// One sliding window per key, created on the first push for a key.
+///
+/// All windows share the same size and multiple. Their elements live in a single pooled
+/// vector of `size * multiple` elements per key, and each window rewinds within its own slot
+/// like the vector backed implementation. Slots of removed keys are reused for new keys,
+/// so the pool only grows when more keys are live at the same time than ever before.
+///
+/// Keys that did not receive an element for `ttl` can be evicted with `evict_idle` or `expire`.
+pub struct KeyedSlidingWindows<K, T, C = SystemClock>
+    where
+        K: Eq + Hash,
+        T: PartialEq + Copy,
+        C: Clock,
+{
+    keys: HashMap<K, usize>,
+    slots: Vec<Slot>,
+    free: Vec<usize>,
+    pool: Vec<MaybeUninit<T>>,
+    size: usize,
+    capacity: usize,
+    ttl: Duration,
+    clock: C,
+}
+
+impl<K, T> KeyedSlidingWindows<K, T, SystemClock>
+    where
+        K: Eq + Hash,
+        T: PartialEq + Copy,
+{
+    /// Creates keyed windows of `size` elements that allocate `size * multiple` elements per key
+    /// and evict keys that were idle for `ttl`.
+    ///
+    /// Fails with `InvalidSize` if `size` is zero, and with `InvalidMultiple` if `multiple` is less than two
+    /// or the capacity per key overflows.
+    pub fn new(size: usize, multiple: usize, ttl: Duration) -> Result<Self, SlidingWindowError>
+    {
+        Self::with_clock(size, multiple, ttl, SystemClock)
+    }
+}
+
+#[allow(clippy::len_without_is_empty)]
+impl<K, T, C> KeyedSlidingWindows<K, T, C>
+    where
+        K: Eq + Hash,
+        T: PartialEq + Copy,
+        C: Clock,
+{
+    /// Creates keyed windows that read the time of the last push from `clock`.
+    /// Fails like `new` for an invalid size or multiple.
+    pub fn with_clock(size: usize, multiple: usize, ttl: Duration, clock: C) -> Result<Self, SlidingWindowError>
+    {
+        let capacity = checked_capacity::<T>(size, multiple)?;
+        Ok(Self {
+            keys: HashMap::new(),
+            slots: Vec::new(),
+            free: Vec::new(),
+            pool: Vec::new(),
+            size,
+            capacity,
+            ttl,
+            clock,
+        })
+    }
+
+    /// Reserves pooled memory for at least `additional` more keys.
+    pub fn reserve(&mut self, additional: usize)
+    {
+        let missing = additional.saturating_sub(self.free.len());
+        self.keys.reserve(additional);
+        self.slots.reserve(missing);
+        self.pool.reserve(missing * self.capacity);
+    }
+
+    /// Pushes a new element to the window of `key`, which is created if it does not exist.
+    /// If the window is filled, its last element will be dropped.
+    pub fn push(&mut self, key: K, value: T)
+    {
+        let now = self.clock.now();
+        let index = match self.keys.get(&key) {
+            Some(&index) => index,
+            None => {
+                let index = self.allocate(now);
+                self.keys.insert(key, index);
+                index
+            }
+        };
+
+        let base = index * self.capacity;
+        let slot = &mut self.slots[index];
+        slot.last_push = now;
+
+        // if the slot is full, rewind the newest size - 1 elements to the front
+        if slot.tail == self.capacity
+        {
+            self.pool.copy_within(base + slot.tail + 1 - self.size..base + slot.tail, base);
+            slot.head = 0;
+            slot.tail = self.size - 1;
+        }
+
+        self.pool[base + slot.tail] = MaybeUninit::new(value);
+        slot.tail += 1;
+
+        if slot.tail - slot.head > self.size
+        {
+            slot.head += 1;
+        }
+    }
+
+    fn allocate(&mut self, now: Duration) -> usize
+    {
+        let slot = Slot { head: 0, tail: 0, last_push: now };
+        match self.free.pop() {
+            Some(index) => {
+                self.slots[index] = slot;
+                index
+            }
+            None => {
+                self.slots.push(slot);
+                self.pool.resize(self.slots.len() * self.capacity, MaybeUninit::uninit());
+                self.slots.len() - 1
+            }
+        }
+    }
+
+    fn window(&self, index: usize) -> &[T]
+    {
+        let slot = &self.slots[index];
+        let base = index * self.capacity;
+        let window = &self.pool[base + slot.head..base + slot.tail];
+        // Safety: slots within head..tail have been written.
+        unsafe { slice::from_raw_parts(window.as_ptr() as *const T, window.len()) }
+    }
+
+    /// Returns the window of `key`, which may not be filled yet,
+    /// or `None` if the key has no window.
+    pub fn get(&self, key: &K) -> Option<&[T]>
+    {
+        self.keys.get(key).map(|&index| self.window(index))
+    }
+
+    /// Returns the filled window of `key`.
+    /// Fails with `Empty` if the key has no window.
+    pub fn slice(&self, key: &K) -> Result<&[T], SlidingWindowError>
+    {
+        let window = self.get(key).ok_or(SlidingWindowError::Empty)?;
+        if window.len() < self.size {
+            Err(SlidingWindowError::NotFilled { len: window.len(), size: self.size })
+        } else {
+            Ok(window)
+        }
+    }
+
+    /// Returns true if the key has a window.
+    pub fn contains_key(&self, key: &K) -> bool
+    {
+        self.keys.contains_key(key)
+    }
+
+    /// Removes the window of `key` and returns its slot to the pool.
+    /// Returns false if the key had no window.
+    pub fn remove(&mut self, key: &K) -> bool
+    {
+        match self.keys.remove(key) {
+            Some(index) => {
+                self.free.push(index);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Removes the windows of all keys without an element for `ttl` until `now`.
+    /// Returns the number of removed keys.
+    pub fn evict_idle(&mut self, now: Duration) -> usize
+    {
+        let before = self.keys.len();
+        let slots = &self.slots;
+        let free = &mut self.free;
+        let ttl = self.ttl;
+
+        self.keys.retain(|_, &mut index| {
+            let idle = now.saturating_sub(slots[index].last_push) >= ttl;
+            if idle {
+                free.push(index);
+            }
+            !idle
+        });
+
+        before - self.keys.len()
+    }
+
+    /// Removes the windows of all keys without an element for `ttl` until the time of the clock.
+    /// Returns the number of removed keys.
+    pub fn expire(&mut self) -> usize
+    {
+        let now = self.clock.now();
+        self.evict_idle(now)
+    }
+
+    /// Returns an iterator over all keys and their current windows, in arbitrary order.
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &[T])> + '_
+    {
+        self.keys.iter().map(|(key, &index)| (key, self.window(index)))
+    }
+
+    /// Returns the number of keys with a window.
+    pub fn len(&self) -> usize
+    {
+        self.keys.len()
+    }
+
+    /// Returns true if no key has a window.
+    pub fn empty(&self) -> bool
+    {
+        self.keys.is_empty()
+    }
+
+    /// Returns the size of every window.
+    pub fn size(&self) -> usize
+    {
+        self.size
+    }
+
+    /// Returns the time after which an idle key is evicted.
+    pub fn ttl(&self) -> Duration
+    {
+        self.ttl
+    }
+
+    /// Returns the clock of the windows.
+    pub fn clock(&self) -> &C
+    {
+        &self.clock
+    }
+}
@@ -1,4 +1,53 @@
+#![no_std]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+pub mod builder;
+#[cfg(all(feature = "alloc", target_has_atomic = "64", target_has_atomic = "ptr"))]
+pub mod concurrent_window;
+pub mod error;
+#[cfg(feature = "alloc")]
+pub mod event_time_window;
+pub mod hopping_window;
+pub mod iter;
+#[cfg(feature = "std")]
+pub mod keyed_sliding_windows;
+pub mod numeric;
+#[cfg(feature = "alloc")]
+mod order_tree;
+pub mod rewind;
+#[cfg(feature = "alloc")]
+pub mod rolling_min_max;
+#[cfg(feature = "alloc")]
+pub mod rolling_quantiles;
+pub mod rolling_stats;
+#[cfg(feature = "serde")]
+mod serialization;
+#[cfg(feature = "std")]
+pub mod session_window;
+pub mod sliding_iter;
 pub mod sliding_window;
 pub mod storage;
 pub mod storage_array;
+pub mod storage_array_uninit;
+#[cfg(feature = "alloc")]
+pub mod storage_mirrored;
+#[cfg(all(feature = "mmap", target_os = "linux"))]
+pub mod storage_mmap;
+#[cfg(feature = "alloc")]
+pub mod storage_owned;
+#[cfg(feature = "alloc")]
+pub mod storage_ring;
+#[cfg(feature = "alloc")]
+pub mod storage_selected;
+#[cfg(feature = "alloc")]
 pub mod storage_vec;
+#[cfg(feature = "futures")]
+pub mod stream;
+#[cfg(feature = "std")]
+pub mod time_window;
+mod uninit_buffer;
+pub mod warm_up;
@@ -0,0 +1,191 @@
+/*
+ * Copyright (c) 2023. Marvin Hansen <marvin.hansen@gmail.com> All rights reserved.
+ */
+
+use alloc::vec::Vec;
+
+const NIL: usize = usize::MAX;
+
+struct Node<T> {
+    value: T,
+    priority: u32,
+    size: usize,
+    left: usize,
+    right: usize,
+}
+
+/// Order statistic tree implemented as treap with subtree sizes.
+///
+/// Nodes live in a single arena vector and are recycled through a free list,
+/// so a tree that never exceeds its initial capacity does not allocate.
+/// Insert, remove, select and rank are expected O(log n).
+/// Values must be totally ordered, i.e. callers must filter out NaN.
+pub(crate) struct OrderTree<T> {
+    nodes: Vec<Node<T>>,
+    free: Vec<usize>,
+    root: usize,
+    seed: u32,
+}
+
+impl<T> OrderTree<T>
+    where
+        T: PartialOrd + Copy,
+{
+    pub(crate) fn with_capacity(capacity: usize) -> Self
+    {
+        Self {
+            nodes: Vec::with_capacity(capacity),
+            free: Vec::with_capacity(capacity),
+            root: NIL,
+            seed: 0x9E37_79B9,
+        }
+    }
+
+    /// Returns the number of values in the tree.
+    pub(crate) fn len(&self) -> usize
+    {
+        self.size(self.root)
+    }
+
+    /// Inserts a value.
+    pub(crate) fn insert(&mut self, value: T)
+    {
+        let node = self.alloc(value);
+        let (left, right) = self.split(self.root, value, false);
+        let left = self.merge(left, node);
+        self.root = self.merge(left, right);
+    }
+
+    /// Removes one occurrence of a value. Returns false if the value was not found.
+    pub(crate) fn remove(&mut self, value: T) -> bool
+    {
+        let (left, rest) = self.split(self.root, value, false);
+        let (mid, right) = self.split(rest, value, true);
+
+        let found = mid != NIL;
+        let mid = if found {
+            let merged = self.merge(self.nodes[mid].left, self.nodes[mid].right);
+            self.free.push(mid);
+            merged
+        } else {
+            mid
+        };
+
+        let left = self.merge(left, mid);
+        self.root = self.merge(left, right);
+        found
+    }
+
+    /// Returns the k-th smallest value, starting at zero.
+    pub(crate) fn select(&self, mut k: usize) -> Option<T>
+    {
+        let mut t = self.root;
+        while t != NIL {
+            let node = &self.nodes[t];
+            let left = self.size(node.left);
+            if k < left {
+                t = node.left;
+            } else if k == left {
+                return Some(node.value);
+            } else {
+                k -= left + 1;
+                t = node.right;
+            }
+        }
+        None
+    }
+
+    /// Returns the number of values strictly less than `value`.
+    pub(crate) fn rank(&self, value: T) -> usize
+    {
+        let mut rank = 0;
+        let mut t = self.root;
+        while t != NIL {
+            let node = &self.nodes[t];
+            if node.value < value {
+                rank += self.size(node.left) + 1;
+                t = node.right;
+            } else {
+                t = node.left;
+            }
+        }
+        rank
+    }
+
+    fn alloc(&mut self, value: T) -> usize
+    {
+        // xorshift32
+        self.seed ^= self.seed << 13;
+        self.seed ^= self.seed >> 17;
+        self.seed ^= self.seed << 5;
+
+        let node = Node { value, priority: self.seed, size: 1, left: NIL, right: NIL };
+        match self.free.pop() {
+            Some(index) => {
+                self.nodes[index] = node;
+                index
+            }
+            None => {
+                self.nodes.push(node);
+                self.nodes.len() - 1
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn size(&self, t: usize) -> usize
+    {
+        if t == NIL { 0 } else { self.nodes[t].size }
+    }
+
+    #[inline(always)]
+    fn update(&mut self, t: usize)
+    {
+        self.nodes[t].size = 1 + self.size(self.nodes[t].left) + self.size(self.nodes[t].right);
+    }
+
+    /// Splits the tree into values less than `value` (or less or equal if `inclusive`) and the rest.
+    fn split(&mut self, t: usize, value: T, inclusive: bool) -> (usize, usize)
+    {
+        if t == NIL {
+            return (NIL, NIL);
+        }
+
+        let node_value = self.nodes[t].value;
+        let goes_left = if inclusive { node_value <= value } else { node_value < value };
+        if goes_left {
+            let (left, right) = self.split(self.nodes[t].right, value, inclusive);
+            self.nodes[t].right = left;
+            self.update(t);
+            (t, right)
+        } else {
+            let (left, right) = self.split(self.nodes[t].left, value, inclusive);
+            self.nodes[t].left = right;
+            self.update(t);
+            (left, t)
+        }
+    }
+
+    /// Merges two trees where all values of `a` are less or equal than those of `b`.
+    fn merge(&mut self, a: usize, b: usize) -> usize
+    {
+        if a == NIL {
+            return b;
+        }
+        if b == NIL {
+            return a;
+        }
+
+        if self.nodes[a].priority > self.nodes[b].priority {
+            let right = self.merge(self.nodes[a].right, b);
+            self.nodes[a].right = right;
+            self.update(a);
+            a
+        } else {
+            let left = self.merge(a, self.nodes[b].left);
+            self.nodes[b].left = left;
+            self.update(b);
+            b
+        }
+    }
+}
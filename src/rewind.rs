@@ -0,0 +1,31 @@
+/*
+ * Copyright (c) 2023. Marvin Hansen <marvin.hansen@gmail.com> All rights reserved.
+ */
+
+/// Defines how the vector and array backed storages move the newest elements to the front
+/// once the tail hits the capacity.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Rewind {
+    /// The newest `size - 1` elements are copied in one go when the tail hits the capacity.
+    #[default]
+    Full,
+    /// Each of the last `size - 1` pushes before the tail hits the capacity also writes its element
+    /// to the position it takes after the rewind, so the rewind itself copies nothing
+    /// and every push does bounded work.
+    /// Requires a capacity of at least `2 * size - 1`, otherwise the storage rewinds as `Full`.
+    Incremental,
+}
+
+impl Rewind {
+    /// Returns the tail position from which a push also writes its element to the front,
+    /// which is `capacity` if no push does.
+    pub(crate) const fn copy_from(self, size: usize, capacity: usize) -> usize
+    {
+        match self {
+            // the front must not overlap the window while it is written
+            Rewind::Incremental if size > 0 && capacity >= size && capacity - size + 1 >= size => capacity - size + 1,
+            _ => capacity,
+        }
+    }
+}
@@ -0,0 +1,131 @@
+/*
+ * Copyright (c) 2023. Marvin Hansen <marvin.hansen@gmail.com> All rights reserved.
+ */
+
+use alloc::collections::VecDeque;
+
+use crate::error::SlidingWindowError;
+use crate::sliding_window::SlidingWindow;
+use crate::storage::Storage;
+
+/// Sliding window that tracks the minimum and maximum of its elements.
+///
+/// Candidates are kept in two monotonic deques of `(sequence, value)` pairs,
+/// which makes push, min and max amortized O(1).
+/// Values that are not comparable with themselves, such as NaN, are ignored.
+pub struct RollingMinMax<S, T>
+    where
+        T: PartialOrd + Copy,
+        S: Storage<T>,
+{
+    window: SlidingWindow<S, T>,
+    min: VecDeque<(usize, T)>,
+    max: VecDeque<(usize, T)>,
+    count: usize,
+}
+
+impl<S, T> RollingMinMax<S, T>
+    where
+        T: PartialOrd + Copy,
+        S: Storage<T>,
+{
+    /// Creates min and max tracking over the given window,
+    /// including all elements the window already holds.
+    pub fn new(window: SlidingWindow<S, T>) -> Self
+    {
+        let size = window.size();
+        let mut tracker = Self {
+            window,
+            min: VecDeque::with_capacity(size),
+            max: VecDeque::with_capacity(size),
+            count: 0,
+        };
+
+        for i in 0..tracker.window.len() {
+            let value = tracker.window[i];
+            tracker.track(value);
+        }
+
+        tracker
+    }
+
+    /// Pushes a new element and updates the minimum and maximum.
+    pub fn push(&mut self, value: T)
+    {
+        self.window.push(value);
+        self.track(value);
+    }
+
+    fn track(&mut self, value: T)
+    {
+        let seq = self.count;
+        self.count += 1;
+
+        // drop candidates that left the window
+        let oldest = self.count.saturating_sub(self.window.size());
+        while self.min.front().map_or(false, |&(s, _)| s < oldest) {
+            self.min.pop_front();
+        }
+        while self.max.front().map_or(false, |&(s, _)| s < oldest) {
+            self.max.pop_front();
+        }
+
+        if value.partial_cmp(&value).is_none() {
+            return;
+        }
+
+        // drop candidates that can never become the min or max again
+        while self.min.back().map_or(false, |&(_, v)| value <= v) {
+            self.min.pop_back();
+        }
+        self.min.push_back((seq, value));
+
+        while self.max.back().map_or(false, |&(_, v)| value >= v) {
+            self.max.pop_back();
+        }
+        self.max.push_back((seq, value));
+    }
+
+    fn position(&self, seq: usize) -> usize
+    {
+        seq - self.count.saturating_sub(self.window.size())
+    }
+
+    /// Returns the minimum of all elements in the window.
+    pub fn min(&self) -> Result<T, SlidingWindowError>
+    {
+        self.min.front().map(|&(_, v)| v).ok_or(SlidingWindowError::Empty)
+    }
+
+    /// Returns the maximum of all elements in the window.
+    pub fn max(&self) -> Result<T, SlidingWindowError>
+    {
+        self.max.front().map(|&(_, v)| v).ok_or(SlidingWindowError::Empty)
+    }
+
+    /// Returns the position of the minimum, counted from the first (oldest) element.
+    /// If the minimum occurs more than once, the newest position is returned.
+    pub fn min_index(&self) -> Result<usize, SlidingWindowError>
+    {
+        self.min.front().map(|&(s, _)| self.position(s)).ok_or(SlidingWindowError::Empty)
+    }
+
+    /// Returns the position of the maximum, counted from the first (oldest) element.
+    /// If the maximum occurs more than once, the newest position is returned.
+    pub fn max_index(&self) -> Result<usize, SlidingWindowError>
+    {
+        self.max.front().map(|&(s, _)| self.position(s)).ok_or(SlidingWindowError::Empty)
+    }
+
+    /// Returns the underlying sliding window.
+    pub fn window(&self) -> &SlidingWindow<S, T>
+    {
+        &self.window
+    }
+
+    /// Consumes the tracker and returns the underlying sliding window.
+    pub fn into_inner(self) -> SlidingWindow<S, T>
+    {
+        self.window
+    }
+}
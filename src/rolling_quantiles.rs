@@ -0,0 +1,138 @@
+/*
+ * Copyright (c) 2023. Marvin Hansen <marvin.hansen@gmail.com> All rights reserved.
+ */
+
+use crate::error::SlidingWindowError;
+use crate::numeric::Numeric;
+use crate::order_tree::OrderTree;
+use crate::sliding_window::SlidingWindow;
+use crate::storage::Storage;
+
+/// Sliding window that maintains the exact order statistics of its elements.
+///
+/// Each push inserts the new element into an order statistic tree and removes the evicted one,
+/// so that push, median, quantile and rank are O(log N).
+///
+/// NaN policy: values that are not comparable with themselves, such as NaN,
+/// still occupy a slot in the window, but are excluded from the order statistics.
+/// Therefore, `count` may be smaller than the number of elements in the window.
+pub struct RollingQuantiles<S, T>
+    where
+        T: Numeric + PartialOrd,
+        S: Storage<T>,
+{
+    window: SlidingWindow<S, T>,
+    tree: OrderTree<T>,
+}
+
+impl<S, T> RollingQuantiles<S, T>
+    where
+        T: Numeric + PartialOrd,
+        S: Storage<T>,
+{
+    /// Creates order statistics over the given window,
+    /// including all elements the window already holds.
+    pub fn new(window: SlidingWindow<S, T>) -> Self
+    {
+        let mut tree = OrderTree::with_capacity(window.size());
+        for &value in window.iter() {
+            if Self::comparable(value) {
+                tree.insert(value);
+            }
+        }
+
+        Self { window, tree }
+    }
+
+    #[inline(always)]
+    fn comparable(value: T) -> bool
+    {
+        value.partial_cmp(&value).is_some()
+    }
+
+    /// Pushes a new element and updates the order statistics.
+    pub fn push(&mut self, value: T)
+    {
+        if self.window.filled() {
+            if let Ok(evicted) = self.window.first() {
+                if Self::comparable(evicted) {
+                    self.tree.remove(evicted);
+                }
+            }
+        }
+
+        self.window.push(value);
+        if Self::comparable(value) {
+            self.tree.insert(value);
+        }
+    }
+
+    /// Returns the number of elements the order statistics cover.
+    pub fn count(&self) -> usize
+    {
+        self.tree.len()
+    }
+
+    /// Returns the median of all elements in the window.
+    pub fn median(&self) -> Result<f64, SlidingWindowError>
+    {
+        self.quantile(0.5)
+    }
+
+    /// Returns the `q`-quantile of all elements in the window, with `q` within `[0, 1]`.
+    ///
+    /// Quantiles between two elements are linearly interpolated,
+    /// which matches the default method of numpy and R (type 7).
+    pub fn quantile(&self, q: f64) -> Result<f64, SlidingWindowError>
+    {
+        if !(0.0..=1.0).contains(&q) {
+            return Err(SlidingWindowError::InvalidQuantile);
+        }
+
+        let n = self.tree.len();
+        if n == 0 {
+            return Err(SlidingWindowError::Empty);
+        }
+
+        let pos = q * (n - 1) as f64;
+        // pos is non-negative, therefore the cast rounds down
+        let lo = pos as usize;
+        let hi = if lo as f64 == pos { lo } else { lo + 1 };
+
+        let lo_value = self.select(lo)?.to_f64();
+        if lo == hi {
+            return Ok(lo_value);
+        }
+
+        let hi_value = self.select(hi)?.to_f64();
+        Ok(lo_value + (hi_value - lo_value) * (pos - lo as f64))
+    }
+
+    /// Returns the k-th smallest element, starting at zero.
+    pub fn select(&self, k: usize) -> Result<T, SlidingWindowError>
+    {
+        match self.tree.select(k) {
+            Some(value) => Ok(value),
+            None if self.tree.len() == 0 => Err(SlidingWindowError::Empty),
+            None => Err(SlidingWindowError::OutOfBounds { index: k, len: self.tree.len() }),
+        }
+    }
+
+    /// Returns the number of elements in the window strictly less than `value`.
+    pub fn rank(&self, value: T) -> usize
+    {
+        self.tree.rank(value)
+    }
+
+    /// Returns the underlying sliding window.
+    pub fn window(&self) -> &SlidingWindow<S, T>
+    {
+        &self.window
+    }
+
+    /// Consumes the order statistics and returns the underlying sliding window.
+    pub fn into_inner(self) -> SlidingWindow<S, T>
+    {
+        self.window
+    }
+}
@@ -0,0 +1,290 @@
+/*
+ * Copyright (c) 2023. Marvin Hansen <marvin.hansen@gmail.com> All rights reserved.
+ */
+
+use crate::error::SlidingWindowError;
+use crate::numeric::Numeric;
+use crate::sliding_window::SlidingWindow;
+use crate::storage::Storage;
+
+#[inline(always)]
+fn abs(value: f64) -> f64
+{
+    if value < 0.0 { -value } else { value }
+}
+
+/// Adds `value` to `sum` with Neumaier compensated summation.
+#[inline(always)]
+fn neumaier_add(sum: &mut f64, compensation: &mut f64, value: f64)
+{
+    let t = *sum + value;
+    if abs(*sum) >= abs(value) {
+        *compensation += (*sum - t) + value;
+    } else {
+        *compensation += (value - t) + *sum;
+    }
+    *sum = t;
+}
+
+/// An evicted element whose squared deviation exceeds the remaining `m2` by this factor
+/// cancels so many bits of `m2` that it is recomputed from the window.
+const CANCELLATION: f64 = (1u64 << 20) as f64;
+
+/// Sliding window that maintains sum, mean and variance of its elements.
+///
+/// Each push updates the aggregates in O(1) by adding the new element and
+/// removing the evicted one. The sum uses Neumaier compensated summation,
+/// and the mean and variance are derived from it with a Welford-style update,
+/// which keeps floating point drift low even over long streams.
+///
+/// The incremental variance cannot recover the precision that a large evicted outlier cancels.
+/// Therefore, sum and variance are recomputed from the window when an evicted element dominates
+/// the variance, which makes that push O(N) in the worst case. All other pushes are O(1).
+///
+/// NaN and infinite elements are counted separately and excluded from the finite aggregates.
+/// While the window holds any, sum, mean and variance are NaN or infinite as they would be
+/// for a direct computation, and they recover once these elements are evicted.
+pub struct RollingStats<S, T>
+    where
+        T: Numeric,
+        S: Storage<T>,
+{
+    window: SlidingWindow<S, T>,
+    len: usize,
+    // number of finite elements, which the sum and m2 cover
+    finite: usize,
+    sum: f64,
+    compensation: f64,
+    m2: f64,
+    nan: usize,
+    pos_inf: usize,
+    neg_inf: usize,
+}
+
+impl<S, T> RollingStats<S, T>
+    where
+        T: Numeric,
+        S: Storage<T>,
+{
+    /// Creates rolling statistics over the given window,
+    /// including all elements the window already holds.
+    pub fn new(window: SlidingWindow<S, T>) -> Self
+    {
+        let mut stats = Self {
+            len: window.len(),
+            window,
+            finite: 0,
+            sum: 0.0,
+            compensation: 0.0,
+            m2: 0.0,
+            nan: 0,
+            pos_inf: 0,
+            neg_inf: 0,
+        };
+
+        for i in 0..stats.window.len() {
+            let value = stats.window[i].to_f64();
+            stats.track_non_finite(value, true);
+        }
+        stats.resync();
+
+        stats
+    }
+
+    /// Pushes a new element and updates the statistics.
+    pub fn push(&mut self, value: T)
+    {
+        let evicted = if self.window.filled() {
+            self.window.first().ok().map(Numeric::to_f64)
+        } else {
+            None
+        };
+
+        self.window.push(value);
+        if evicted.is_none() {
+            self.len += 1;
+        }
+
+        let value = value.to_f64();
+        let evicted = evicted.filter(|&evicted| !self.track_non_finite(evicted, false));
+        let resync = match (self.track_non_finite(value, true), evicted) {
+            (false, Some(evicted)) => self.replace(value, evicted),
+            (false, None) => {
+                self.insert(value);
+                false
+            }
+            (true, Some(evicted)) => self.remove(evicted),
+            (true, None) => false,
+        };
+
+        if resync {
+            self.resync();
+        }
+    }
+
+    /// Counts a non-finite value into or out of the window.
+    /// Returns false for a finite value, which is left to the finite aggregates.
+    fn track_non_finite(&mut self, value: f64, added: bool) -> bool
+    {
+        let count = if value.is_nan() {
+            &mut self.nan
+        } else if value == f64::INFINITY {
+            &mut self.pos_inf
+        } else if value == f64::NEG_INFINITY {
+            &mut self.neg_inf
+        } else {
+            return false;
+        };
+
+        if added { *count += 1 } else { *count -= 1 }
+        true
+    }
+
+    /// Adds a finite value.
+    fn insert(&mut self, value: f64)
+    {
+        let old_mean = self.raw_mean();
+        neumaier_add(&mut self.sum, &mut self.compensation, value);
+        self.finite += 1;
+        let new_mean = self.raw_mean();
+        self.m2 += (value - old_mean) * (value - new_mean);
+    }
+
+    /// Removes a finite value. Returns true if the variance needs to be recomputed.
+    fn remove(&mut self, evicted: f64) -> bool
+    {
+        let old_mean = self.raw_mean();
+        neumaier_add(&mut self.sum, &mut self.compensation, -evicted);
+        self.finite -= 1;
+        let new_mean = self.raw_mean();
+        self.m2 -= (evicted - old_mean) * (evicted - new_mean);
+        self.cancelled(evicted, old_mean)
+    }
+
+    /// Adds a finite value and removes a finite evicted value.
+    /// Returns true if the variance needs to be recomputed.
+    fn replace(&mut self, value: f64, evicted: f64) -> bool
+    {
+        let old_mean = self.raw_mean();
+        neumaier_add(&mut self.sum, &mut self.compensation, value);
+        neumaier_add(&mut self.sum, &mut self.compensation, -evicted);
+        let new_mean = self.raw_mean();
+        self.m2 += (value - evicted) * (value - new_mean + evicted - old_mean);
+        self.cancelled(evicted, old_mean)
+    }
+
+    /// Clamps the variance and returns true if the evicted value dominated it,
+    /// in which case the remaining `m2` is mostly rounding error.
+    fn cancelled(&mut self, evicted: f64, old_mean: f64) -> bool
+    {
+        // Guard against negative variance caused by rounding.
+        if self.m2 < 0.0 {
+            self.m2 = 0.0;
+        }
+
+        // rounding error of a window of equal values, which needs no recomputation
+        let floor = f64::EPSILON * old_mean * old_mean * self.finite as f64;
+        let deviation = evicted - old_mean;
+        deviation * deviation > CANCELLATION * if self.m2 > floor { self.m2 } else { floor }
+    }
+
+    /// Recomputes the finite aggregates from the window contents.
+    fn resync(&mut self)
+    {
+        let mut finite = 0;
+        let mut sum = 0.0;
+        let mut compensation = 0.0;
+        for value in self.window.iter().map(|v| v.to_f64()).filter(|v| v.is_finite()) {
+            neumaier_add(&mut sum, &mut compensation, value);
+            finite += 1;
+        }
+
+        let mean = if finite == 0 { 0.0 } else { (sum + compensation) / finite as f64 };
+        let mut m2 = 0.0;
+        let mut m2_compensation = 0.0;
+        for value in self.window.iter().map(|v| v.to_f64()).filter(|v| v.is_finite()) {
+            neumaier_add(&mut m2, &mut m2_compensation, (value - mean) * (value - mean));
+        }
+
+        self.finite = finite;
+        self.sum = sum;
+        self.compensation = compensation;
+        self.m2 = m2 + m2_compensation;
+    }
+
+    fn raw_mean(&self) -> f64
+    {
+        if self.finite == 0 {
+            0.0
+        } else {
+            (self.sum + self.compensation) / self.finite as f64
+        }
+    }
+
+    /// Returns the sum of the non-finite elements, or None if the window holds none.
+    fn non_finite_sum(&self) -> Option<f64>
+    {
+        if self.nan > 0 || (self.pos_inf > 0 && self.neg_inf > 0) {
+            Some(f64::NAN)
+        } else if self.pos_inf > 0 {
+            Some(f64::INFINITY)
+        } else if self.neg_inf > 0 {
+            Some(f64::NEG_INFINITY)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the number of elements the statistics cover.
+    pub fn count(&self) -> usize
+    {
+        self.len
+    }
+
+    /// Returns the sum of all elements in the window.
+    pub fn sum(&self) -> f64
+    {
+        self.non_finite_sum().unwrap_or(self.sum + self.compensation)
+    }
+
+    /// Returns the arithmetic mean of all elements in the window.
+    pub fn mean(&self) -> Result<f64, SlidingWindowError>
+    {
+        if self.len == 0 {
+            Err(SlidingWindowError::Empty)
+        } else {
+            Ok(self.non_finite_sum().unwrap_or(self.raw_mean()))
+        }
+    }
+
+    /// Returns the population variance of all elements in the window.
+    pub fn variance(&self) -> Result<f64, SlidingWindowError>
+    {
+        if self.len == 0 {
+            Err(SlidingWindowError::Empty)
+        } else if self.finite < self.len {
+            Ok(f64::NAN)
+        } else {
+            Ok(self.m2 / self.len as f64)
+        }
+    }
+
+    /// Returns the population standard deviation of all elements in the window.
+    #[cfg(feature = "std")]
+    pub fn std_dev(&self) -> Result<f64, SlidingWindowError>
+    {
+        self.variance().map(f64::sqrt)
+    }
+
+    /// Returns the underlying sliding window.
+    pub fn window(&self) -> &SlidingWindow<S, T>
+    {
+        &self.window
+    }
+
+    /// Consumes the statistics and returns the underlying sliding window.
+    pub fn into_inner(self) -> SlidingWindow<S, T>
+    {
+        self.window
+    }
+}
@@ -0,0 +1,205 @@
+/*
+ * Copyright (c) 2023. Marvin Hansen <marvin.hansen@gmail.com> All rights reserved.
+ */
+
+//! Serde support for sliding windows and their storage.
+//!
+//! A storage serializes its configuration together with the logical window contents,
+//! from the first (oldest) to the last (newest) element. Deserialization rebuilds the
+//! storage by pushing the elements and rejects data that violates the configuration.
+
+use core::fmt;
+use core::marker::PhantomData;
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use serde::de::{self, SeqAccess, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::rewind::Rewind;
+use crate::sliding_window::SlidingWindow;
+use crate::storage::Storage;
+use crate::storage_array::ArrayStorage;
+#[cfg(feature = "alloc")]
+use crate::storage_vec::VectorStorage;
+use crate::warm_up::WarmUp;
+
+#[derive(Serialize)]
+struct WindowRef<'a, S, T> {
+    storage: &'a S,
+    warm_up: &'a WarmUp<T>,
+}
+
+#[derive(Deserialize)]
+struct WindowRepr<S, T> {
+    storage: S,
+    warm_up: WarmUp<T>,
+}
+
+impl<S, T> Serialize for SlidingWindow<S, T>
+    where
+        T: Serialize,
+        S: Storage<T> + Serialize,
+{
+    fn serialize<R: Serializer>(&self, serializer: R) -> Result<R::Ok, R::Error> {
+        WindowRef { storage: self.storage(), warm_up: self.warm_up() }.serialize(serializer)
+    }
+}
+
+impl<'de, S, T> Deserialize<'de> for SlidingWindow<S, T>
+    where
+        T: Deserialize<'de>,
+        S: Storage<T> + Deserialize<'de>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let repr = WindowRepr::<S, T>::deserialize(deserializer)?;
+
+        if let WarmUp::Padded(_) = repr.warm_up {
+            if !repr.storage.filled() {
+                return Err(de::Error::custom("padded sliding window must be filled"));
+            }
+        }
+
+        Ok(SlidingWindow::from_parts(repr.storage, repr.warm_up))
+    }
+}
+
+#[derive(Serialize)]
+struct ArrayRef<'a, T> {
+    size: usize,
+    capacity: usize,
+    rewind: Rewind,
+    elements: &'a [T],
+}
+
+#[derive(Deserialize)]
+struct ArrayRepr<T, const SIZE: usize, const CAPACITY: usize>
+    where
+        T: PartialEq + Copy,
+{
+    size: usize,
+    capacity: usize,
+    #[serde(default)]
+    rewind: Rewind,
+    elements: ArrayElements<T, SIZE, CAPACITY>,
+}
+
+/// Deserializes a sequence directly into an array storage, which requires no allocation.
+struct ArrayElements<T, const SIZE: usize, const CAPACITY: usize>(ArrayStorage<T, SIZE, CAPACITY>)
+    where
+        T: PartialEq + Copy;
+
+impl<T, const SIZE: usize, const CAPACITY: usize> Serialize for ArrayStorage<T, SIZE, CAPACITY>
+    where
+        T: PartialEq + Copy + Serialize,
+{
+    fn serialize<R: Serializer>(&self, serializer: R) -> Result<R::Ok, R::Error> {
+        ArrayRef { size: SIZE, capacity: CAPACITY, rewind: self.rewind(), elements: self.get_slice() }.serialize(serializer)
+    }
+}
+
+impl<'de, T, const SIZE: usize, const CAPACITY: usize> Deserialize<'de> for ArrayStorage<T, SIZE, CAPACITY>
+    where
+        T: PartialEq + Copy + Deserialize<'de>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let repr = ArrayRepr::<T, SIZE, CAPACITY>::deserialize(deserializer)?;
+
+        if repr.size != SIZE {
+            return Err(de::Error::invalid_value(de::Unexpected::Unsigned(repr.size as u64), &"the SIZE of the array storage"));
+        }
+        if repr.capacity != CAPACITY {
+            return Err(de::Error::invalid_value(de::Unexpected::Unsigned(repr.capacity as u64), &"the CAPACITY of the array storage"));
+        }
+
+        let mut storage = repr.elements.0;
+        storage.set_rewind(repr.rewind);
+        Ok(storage)
+    }
+}
+
+impl<'de, T, const SIZE: usize, const CAPACITY: usize> Deserialize<'de> for ArrayElements<T, SIZE, CAPACITY>
+    where
+        T: PartialEq + Copy + Deserialize<'de>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct ElementsVisitor<T, const SIZE: usize, const CAPACITY: usize>(PhantomData<T>);
+
+        impl<'de, T, const SIZE: usize, const CAPACITY: usize> Visitor<'de> for ElementsVisitor<T, SIZE, CAPACITY>
+            where
+                T: PartialEq + Copy + Deserialize<'de>,
+        {
+            type Value = ArrayElements<T, SIZE, CAPACITY>;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "a sequence of at most {} elements", SIZE)
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                let mut storage = ArrayStorage::new();
+                let mut len = 0;
+                while let Some(value) = seq.next_element()? {
+                    if len == SIZE {
+                        return Err(de::Error::invalid_length(len + 1, &self));
+                    }
+                    storage.push(value);
+                    len += 1;
+                }
+                Ok(ArrayElements(storage))
+            }
+        }
+
+        deserializer.deserialize_seq(ElementsVisitor(PhantomData))
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[derive(Serialize)]
+struct VectorRef<'a, T> {
+    size: usize,
+    multiple: usize,
+    rewind: Rewind,
+    elements: &'a [T],
+}
+
+#[cfg(feature = "alloc")]
+#[derive(Deserialize)]
+struct VectorRepr<T> {
+    size: usize,
+    multiple: usize,
+    #[serde(default)]
+    rewind: Rewind,
+    elements: Vec<T>,
+}
+
+#[cfg(feature = "alloc")]
+impl<T> Serialize for VectorStorage<T>
+    where
+        T: PartialEq + Copy + Serialize,
+{
+    fn serialize<R: Serializer>(&self, serializer: R) -> Result<R::Ok, R::Error> {
+        VectorRef { size: self.size(), multiple: self.multiple(), rewind: self.rewind(), elements: self.get_slice() }.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'de, T> Deserialize<'de> for VectorStorage<T>
+    where
+        T: PartialEq + Copy + Deserialize<'de>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let repr = VectorRepr::<T>::deserialize(deserializer)?;
+
+        if repr.elements.len() > repr.size {
+            return Err(de::Error::invalid_length(repr.elements.len(), &"at most size elements"));
+        }
+
+        // Same validation as the builder, so that untrusted input cannot abort on allocation.
+        let mut storage = VectorStorage::try_with_rewind(repr.size, repr.multiple, repr.rewind)
+            .map_err(de::Error::custom)?;
+        for value in repr.elements {
+            storage.push(value);
+        }
+        Ok(storage)
+    }
+}
@@ -0,0 +1,197 @@
+/*
+ * Copyright (c) 2023. Marvin Hansen <marvin.hansen@gmail.com> All rights reserved.
+ */
+
+use alloc::vec::Vec;
+use core::mem;
+use core::time::Duration;
+
+use crate::time_window::{Clock, SystemClock};
+
+/// A closed (or the current) session, i.e. its `(timestamp, value)` pairs.
+/// A session always holds at least one element.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Session<'a, T> {
+    entries: &'a [(Duration, T)],
+}
+
+#[allow(clippy::len_without_is_empty)]
+impl<'a, T> Session<'a, T>
+    where
+        T: Copy,
+{
+    /// Returns the timestamp of the first element.
+    pub fn start(&self) -> Duration {
+        self.entries[0].0
+    }
+
+    /// Returns the timestamp of the last element.
+    pub fn end(&self) -> Duration {
+        self.entries[self.entries.len() - 1].0
+    }
+
+    /// Returns the time span between the first and the last element.
+    pub fn duration(&self) -> Duration {
+        self.end() - self.start()
+    }
+
+    /// Returns the number of elements in the session.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns the session as slice of `(timestamp, value)` pairs.
+    pub fn entries(&self) -> &'a [(Duration, T)] {
+        self.entries
+    }
+
+    /// Returns an iterator over the values of the session, from the first to the last.
+    pub fn values(&self) -> impl Iterator<Item = T> + 'a {
+        self.entries.iter().map(|(_, v)| *v)
+    }
+
+    /// Returns the values of the session as vector.
+    pub fn vec(&self) -> Vec<T> {
+        self.values().collect()
+    }
+}
+
+/// Window that groups elements into sessions separated by inactivity.
+///
+/// An element joins the current session unless no element arrived for `gap`,
+/// or the session would span `max_duration` or more. In that case, the current session
+/// is closed and the element starts a new session.
+/// Sessions are stored in two pre-allocated vectors, one for the current and one for
+/// the last closed session, which are swapped on close, so closing does not allocate.
+/// Timestamps are expected to be non-decreasing.
+pub struct SessionWindow<T, C = SystemClock>
+    where
+        T: PartialEq + Copy,
+        C: Clock,
+{
+    current: Vec<(Duration, T)>,
+    closed: Vec<(Duration, T)>,
+    gap: Duration,
+    max_duration: Duration,
+    clock: C,
+}
+
+impl<T> SessionWindow<T, SystemClock>
+    where
+        T: PartialEq + Copy,
+{
+    /// Creates a new session window that pre-allocates `capacity` elements per session.
+    pub fn new(gap: Duration, max_duration: Duration, capacity: usize) -> Self
+    {
+        Self::with_clock(gap, max_duration, capacity, SystemClock)
+    }
+}
+
+impl<T, C> SessionWindow<T, C>
+    where
+        T: PartialEq + Copy,
+        C: Clock,
+{
+    /// Creates a new session window that reads timestamps from `clock`.
+    pub fn with_clock(gap: Duration, max_duration: Duration, capacity: usize, clock: C) -> Self
+    {
+        Self {
+            current: Vec::with_capacity(capacity),
+            closed: Vec::with_capacity(capacity),
+            gap,
+            max_duration,
+            clock,
+        }
+    }
+
+    /// Pushes a new element stamped with the current time of the clock.
+    /// Returns the session that was closed by this element, if any.
+    pub fn push(&mut self, value: T) -> Option<Session<'_, T>> {
+        let now = self.clock.now();
+        self.push_at(now, value)
+    }
+
+    /// Pushes a new element with an explicit (event) timestamp.
+    /// Returns the session that was closed by this element, if any.
+    pub fn push_at(&mut self, timestamp: Duration, value: T) -> Option<Session<'_, T>> {
+        let closes = match (self.current.first(), self.current.last()) {
+            (Some(&(start, _)), Some(&(last, _))) => {
+                timestamp.saturating_sub(last) >= self.gap
+                    || timestamp.saturating_sub(start) >= self.max_duration
+            }
+            _ => false,
+        };
+
+        if closes {
+            self.swap();
+        }
+        self.current.push((timestamp, value));
+
+        if closes { self.last_closed() } else { None }
+    }
+
+    /// Closes the current session if no element arrived for `gap` until `now`.
+    /// Returns the closed session, if any.
+    pub fn close_idle(&mut self, now: Duration) -> Option<Session<'_, T>> {
+        match self.current.last() {
+            Some(&(last, _)) if now.saturating_sub(last) >= self.gap => self.close(),
+            _ => None,
+        }
+    }
+
+    /// Closes the current session if no element arrived for `gap` until the time of the clock.
+    /// Returns the closed session, if any.
+    pub fn expire(&mut self) -> Option<Session<'_, T>> {
+        let now = self.clock.now();
+        self.close_idle(now)
+    }
+
+    /// Closes the current session regardless of activity, i.e. at the end of a stream.
+    /// Returns the closed session, or `None` if there was no current session.
+    pub fn close(&mut self) -> Option<Session<'_, T>> {
+        if self.current.is_empty() {
+            return None;
+        }
+
+        self.swap();
+        self.last_closed()
+    }
+
+    fn swap(&mut self) {
+        mem::swap(&mut self.current, &mut self.closed);
+        self.current.clear();
+    }
+
+    /// Returns the current, still open, session.
+    pub fn current(&self) -> Option<Session<'_, T>> {
+        if self.current.is_empty() {
+            None
+        } else {
+            Some(Session { entries: &self.current })
+        }
+    }
+
+    /// Returns the last closed session.
+    pub fn last_closed(&self) -> Option<Session<'_, T>> {
+        if self.closed.is_empty() {
+            None
+        } else {
+            Some(Session { entries: &self.closed })
+        }
+    }
+
+    /// Returns the inactivity gap that closes a session.
+    pub fn gap(&self) -> Duration {
+        self.gap
+    }
+
+    /// Returns the time span at which a session is closed regardless of activity.
+    pub fn max_duration(&self) -> Duration {
+        self.max_duration
+    }
+
+    /// Returns the clock of the window.
+    pub fn clock(&self) -> &C {
+        &self.clock
+    }
+}
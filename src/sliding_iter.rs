@@ -0,0 +1,151 @@
+/*
+ * Copyright (c) 2023. Marvin Hansen <marvin.hansen@gmail.com> All rights reserved.
+ */
+
+//! Sliding windows over the items of any iterator.
+//!
+//! Unlike `slice::windows`, the items do not need to be in memory up front.
+//! Every item is pushed into a sliding window, so the usual amortized rewind applies,
+//! and each full window is a slice over the backing storage.
+
+use core::iter::FusedIterator;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::sliding_window::{new_with_array_storage, SlidingWindow};
+#[cfg(feature = "alloc")]
+use crate::sliding_window::new_with_vector_storage;
+use crate::storage::Storage;
+use crate::storage_array::ArrayStorage;
+#[cfg(feature = "alloc")]
+use crate::storage_vec::VectorStorage;
+
+/// Sliding windows over the items of an iterator.
+///
+/// `next_window` and `for_each_window` lend each full window as a slice without allocating.
+/// As an `Iterator`, the vector backed variant yields each full window as a `Vec`,
+/// and the array backed variant as a fixed size array.
+pub struct Sliding<I, S>
+    where
+        I: Iterator,
+        S: Storage<I::Item>,
+{
+    iter: I,
+    window: SlidingWindow<S, I::Item>,
+}
+
+impl<I, S> Sliding<I, S>
+    where
+        I: Iterator,
+        S: Storage<I::Item>,
+{
+    /// Advances to the next full window and returns it as a slice, from the first (oldest)
+    /// to the last (newest) element. Returns `None` once the iterator is exhausted.
+    pub fn next_window(&mut self) -> Option<&[I::Item]>
+    {
+        loop {
+            let value = self.iter.next()?;
+            self.window.push(value);
+            if self.window.filled() {
+                break;
+            }
+        }
+
+        self.window.slice().ok()
+    }
+
+    /// Calls `f` with every remaining full window.
+    pub fn for_each_window<F>(mut self, mut f: F)
+        where
+            F: FnMut(&[I::Item]),
+    {
+        while let Some(window) = self.next_window() {
+            f(window);
+        }
+    }
+
+    /// Returns the sliding window.
+    pub fn window(&self) -> &SlidingWindow<S, I::Item>
+    {
+        &self.window
+    }
+
+    fn windows_hint(&self) -> (usize, Option<usize>)
+    {
+        // Items that have to be pushed before the first window is full.
+        let missing = self.window.size().saturating_sub(self.window.len() + 1);
+        let (lower, upper) = self.iter.size_hint();
+        (lower.saturating_sub(missing), upper.map(|upper| upper.saturating_sub(missing)))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I> Iterator for Sliding<I, VectorStorage<I::Item>>
+    where
+        I: Iterator,
+        I::Item: PartialEq + Copy,
+{
+    type Item = Vec<I::Item>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_window().map(<[I::Item]>::to_vec)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.windows_hint()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I> FusedIterator for Sliding<I, VectorStorage<I::Item>>
+    where
+        I: FusedIterator,
+        I::Item: PartialEq + Copy,
+{}
+
+impl<I, const SIZE: usize, const CAPACITY: usize> Iterator for Sliding<I, ArrayStorage<I::Item, SIZE, CAPACITY>>
+    where
+        I: Iterator,
+        I::Item: PartialEq + Copy,
+{
+    type Item = [I::Item; SIZE];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_window().map(|window| core::array::from_fn(|i| window[i]))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.windows_hint()
+    }
+}
+
+impl<I, const SIZE: usize, const CAPACITY: usize> FusedIterator for Sliding<I, ArrayStorage<I::Item, SIZE, CAPACITY>>
+    where
+        I: FusedIterator,
+        I::Item: PartialEq + Copy,
+{}
+
+/// Extension trait that adds sliding windows to every iterator.
+pub trait SlidingIteratorExt: Iterator
+{
+    /// Returns sliding windows of `size` elements backed by a vector of `size * multiple` elements.
+    #[cfg(feature = "alloc")]
+    fn sliding(self, size: usize, multiple: usize) -> Sliding<Self, VectorStorage<Self::Item>>
+        where
+            Self: Sized,
+            Self::Item: PartialEq + Copy,
+    {
+        Sliding { iter: self, window: new_with_vector_storage(size, multiple) }
+    }
+
+    /// Returns sliding windows of `SIZE` elements backed by an array of `CAPACITY` elements.
+    fn sliding_array<const SIZE: usize, const CAPACITY: usize>(self) -> Sliding<Self, ArrayStorage<Self::Item, SIZE, CAPACITY>>
+        where
+            Self: Sized,
+            Self::Item: PartialEq + Copy,
+    {
+        Sliding { iter: self, window: new_with_array_storage() }
+    }
+}
+
+impl<I> SlidingIteratorExt for I where I: Iterator {}
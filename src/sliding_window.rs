@@ -1,9 +1,30 @@
-use std::marker::PhantomData;
+use core::iter::Rev;
+use core::marker::PhantomData;
+use core::ops::{Index, Sub};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use crate::error::SlidingWindowError;
+use crate::iter::Iter;
+use crate::rewind::Rewind;
 use crate::storage::{Storage};
 use crate::storage_array::ArrayStorage;
+use crate::storage_array_uninit::UninitArrayStorage;
+#[cfg(feature = "alloc")]
+use crate::storage_mirrored::MirroredStorage;
+#[cfg(all(feature = "mmap", target_os = "linux"))]
+use crate::storage_mmap::MmapStorage;
+#[cfg(feature = "alloc")]
+use crate::storage_owned::OwnedStorage;
+#[cfg(feature = "alloc")]
+use crate::storage_ring::RingStorage;
+#[cfg(feature = "alloc")]
+use crate::storage_selected::SelectedStorage;
+#[cfg(feature = "alloc")]
 use crate::storage_vec::VectorStorage;
+use crate::warm_up::WarmUp;
 
-pub fn new_with_vector_storage<T: PartialEq + Copy + Default >(
+#[cfg(feature = "alloc")]
+pub fn new_with_vector_storage<T: PartialEq + Copy >(
     size: usize,
     multiple: usize,
 )
@@ -14,42 +35,192 @@ pub fn new_with_vector_storage<T: PartialEq + Copy + Default >(
     )
 }
 
-pub fn new_with_array_storage<T: PartialEq + Copy + Default ,  const SIZE: usize,const CAPACITY: usize>(
+pub const fn new_with_uninit_array_storage<T, const SIZE: usize, const CAPACITY: usize>(
+)
+    -> SlidingWindow<UninitArrayStorage<T, SIZE, CAPACITY>, T>
+{
+
+    assert!(AssertCapacity::<SIZE, CAPACITY>::OK);
+
+    SlidingWindow::with_storage(
+        UninitArrayStorage::new()
+    )
+}
+
+#[cfg(feature = "alloc")]
+pub fn new_with_owned_storage<T>(
+    size: usize,
+    multiple: usize,
+)
+    -> SlidingWindow<OwnedStorage<T>, T>
+{
+    SlidingWindow::with_storage(
+        OwnedStorage::new(size, multiple)
+    )
+}
+
+#[cfg(feature = "alloc")]
+pub fn new_with_ring_storage<T: PartialEq + Copy >(
+    size: usize,
+)
+    -> SlidingWindow<RingStorage<T>, T>
+{
+    SlidingWindow::with_storage(
+        RingStorage::new(size)
+    )
+}
+
+#[cfg(feature = "alloc")]
+pub fn new_with_mirrored_storage<T: PartialEq + Copy >(
+    size: usize,
+)
+    -> SlidingWindow<MirroredStorage<T>, T>
+{
+    SlidingWindow::with_storage(
+        MirroredStorage::new(size)
+    )
+}
+
+#[cfg(all(feature = "mmap", target_os = "linux"))]
+pub fn new_with_mmap_storage<T: PartialEq + Copy >(
+    size: usize,
+)
+    -> SlidingWindow<MmapStorage<T>, T>
+{
+    SlidingWindow::with_storage(
+        MmapStorage::new(size)
+    )
+}
+
+pub const fn new_with_array_storage<T: PartialEq + Copy ,  const SIZE: usize,const CAPACITY: usize>(
 )
     -> SlidingWindow<ArrayStorage<T, SIZE, CAPACITY>, T>
 {
 
-    assert!(CAPACITY > SIZE);
+    assert!(AssertCapacity::<SIZE, CAPACITY>::OK);
 
     SlidingWindow::with_storage(
         ArrayStorage::new()
     )
 }
 
+#[cfg(feature = "alloc")]
+pub fn new_with_vector_storage_and_warm_up<T: PartialEq + Copy >(
+    size: usize,
+    multiple: usize,
+    warm_up: WarmUp<T>,
+)
+    -> SlidingWindow<VectorStorage<T>, T>
+{
+    SlidingWindow::with_storage_and_warm_up(
+        VectorStorage::new(size, multiple),
+        warm_up,
+    )
+}
+
+pub fn new_with_array_storage_and_warm_up<T: PartialEq + Copy ,  const SIZE: usize,const CAPACITY: usize>(
+    warm_up: WarmUp<T>,
+)
+    -> SlidingWindow<ArrayStorage<T, SIZE, CAPACITY>, T>
+{
+
+    assert!(AssertCapacity::<SIZE, CAPACITY>::OK);
+
+    SlidingWindow::with_storage_and_warm_up(
+        ArrayStorage::new(),
+        warm_up,
+    )
+}
+
+#[cfg(feature = "alloc")]
+pub fn new_with_vector_storage_and_rewind<T: PartialEq + Copy >(
+    size: usize,
+    multiple: usize,
+    rewind: Rewind,
+)
+    -> SlidingWindow<VectorStorage<T>, T>
+{
+    SlidingWindow::with_storage(
+        VectorStorage::with_rewind(size, multiple, rewind)
+    )
+}
+
+pub const fn new_with_array_storage_and_rewind<T: PartialEq + Copy ,  const SIZE: usize,const CAPACITY: usize>(
+    rewind: Rewind,
+)
+    -> SlidingWindow<ArrayStorage<T, SIZE, CAPACITY>, T>
+{
+
+    assert!(AssertCapacity::<SIZE, CAPACITY>::OK);
+
+    SlidingWindow::with_storage(
+        ArrayStorage::with_rewind(rewind)
+    )
+}
+
+/// Fails the build if a const generic window configuration is invalid,
+/// i.e. if `SIZE` is zero or `CAPACITY` is not greater than `SIZE`.
+pub(crate) struct AssertCapacity<const SIZE: usize, const CAPACITY: usize>;
+
+impl<const SIZE: usize, const CAPACITY: usize> AssertCapacity<SIZE, CAPACITY>
+{
+    pub(crate) const OK: bool = {
+        assert!(SIZE > 0 && CAPACITY > SIZE, "CAPACITY must be greater than SIZE, and SIZE greater than zero");
+        true
+    };
+}
+
 pub struct SlidingWindow<S, T>
     where
-        T: PartialEq + Copy + Default ,
         S: Storage<T>,
 {
     storage: S,
+    warm_up: WarmUp<T>,
     ty: PhantomData<T>,
 }
 
 impl<S, T> SlidingWindow<S, T>
     where
-        T: PartialEq + Copy + Default ,
         S: Storage<T>,
 {
-    pub(crate) fn with_storage(storage: S) -> Self
+    pub(crate) const fn with_storage(storage: S) -> Self
+    {
+        Self { storage, warm_up: WarmUp::Strict, ty: PhantomData }
+    }
+
+    pub(crate) const fn from_parts(storage: S, warm_up: WarmUp<T>) -> Self
+    {
+        Self { storage, warm_up, ty: PhantomData }
+    }
+
+    #[cfg(any(feature = "serde", feature = "futures"))]
+    pub(crate) fn storage(&self) -> &S
+    {
+        &self.storage
+    }
+}
+
+impl<S, T> SlidingWindow<S, T>
+    where
+        T: Clone,
+        S: Storage<T>,
+{
+    pub(crate) fn with_storage_and_warm_up(mut storage: S, warm_up: WarmUp<T>) -> Self
     {
-        Self { storage, ty: Default::default() }
+        if let WarmUp::Padded(value) = &warm_up {
+            for _ in 0..storage.size() {
+                storage.push(value.clone());
+            }
+        }
+
+        Self::from_parts(storage, warm_up)
     }
 }
 
 
+#[allow(clippy::len_without_is_empty)]
 impl<S, T> SlidingWindow<S, T>
     where
-        T: PartialEq + Copy + Default,
         S: Storage<T>,
 {
     pub fn push(&mut self, value: T)
@@ -57,14 +228,38 @@ impl<S, T> SlidingWindow<S, T>
         self.storage.push(value)
     }
 
-    pub fn first(&self) -> Result<T, String>
+    /// Pushes a new element and hands back the evicted element, if any.
+    pub fn push_evict(&mut self, value: T) -> Option<T>
     {
-        self.storage.first()
+        self.storage.push_evict(value)
+    }
+
+    pub fn first_ref(&self) -> Result<&T, SlidingWindowError>
+    {
+        self.storage.first_ref()
+    }
+
+    pub fn last_ref(&self) -> Result<&T, SlidingWindowError>
+    {
+        match self.warm_up {
+            WarmUp::Partial => {
+                let (front, back) = self.storage.as_slices();
+                back.last().or(front.last()).ok_or(SlidingWindowError::Empty)
+            }
+            _ => self.storage.last_ref(),
+        }
+    }
+
+    /// Returns the warm-up policy of the window.
+    pub fn warm_up(&self) -> &WarmUp<T>
+    {
+        &self.warm_up
     }
 
-    pub fn last(&self) -> Result<T, String>
+    /// Returns the number of elements in the window.
+    pub fn len(&self) -> usize
     {
-        self.storage.last()
+        self.storage.len()
     }
 
     pub fn empty(&self) -> bool
@@ -82,15 +277,254 @@ impl<S, T> SlidingWindow<S, T>
         self.storage.size()
     }
 
-    pub fn arr<const SIZE: usize>(&self) -> Result<[T; SIZE], String> {
-        self.storage.arr()
+    pub fn iter(&self) -> Iter<'_, T> {
+        self.storage.iter()
+    }
+
+    pub fn iter_newest_first(&self) -> Rev<Iter<'_, T>> {
+        self.storage.iter_newest_first()
+    }
+
+    pub fn slice(&self) -> Result<&[T], SlidingWindowError> {
+        match (&self.warm_up, self.storage.as_slices()) {
+            (WarmUp::Partial, (front, [])) => Ok(front),
+            (WarmUp::Partial, _) => Err(SlidingWindowError::NotContiguous),
+            _ => self.storage.slice(),
+        }
+    }
+
+    /// Returns the window as two slices, from the first (oldest) to the last (newest) element.
+    /// The second slice is only non-empty for storages that wrap around, such as a ring buffer.
+    pub fn as_slices(&self) -> (&[T], &[T]) {
+        self.storage.as_slices()
+    }
+
+    /// Rearranges the storage so that the whole window is contiguous and returns it as a slice,
+    /// regardless of the warm-up policy.
+    pub fn make_contiguous(&mut self) -> &[T] {
+        self.storage.make_contiguous()
+    }
+}
+
+impl<S, T> SlidingWindow<S, T>
+    where
+        T: Clone,
+        S: Storage<T>,
+{
+    pub fn first(&self) -> Result<T, SlidingWindowError>
+    {
+        self.storage.first()
+    }
+
+    pub fn last(&self) -> Result<T, SlidingWindowError>
+    {
+        match self.warm_up {
+            WarmUp::Partial => self.last_ref().cloned(),
+            _ => self.storage.last(),
+        }
+    }
+
+    /// Seeds the window from historical data, oldest first.
+    /// Only the newest `size` elements of `history` are retained.
+    pub fn prefill_from(&mut self, history: &[T])
+    {
+        let start = history.len().saturating_sub(self.storage.size());
+        for value in &history[start..] {
+            self.storage.push(value.clone());
+        }
+    }
+
+    pub fn get(&self, index: usize) -> Result<T, SlidingWindowError> {
+        self.storage.get(index)
+    }
+
+    /// # Safety
+    ///
+    /// `index` must be less than the number of elements in the window.
+    pub unsafe fn get_unchecked(&self, index: usize) -> T {
+        self.storage.get_unchecked(index)
+    }
+
+    pub fn get_back(&self, k: usize) -> Result<T, SlidingWindowError> {
+        self.storage.get_back(k)
+    }
+
+    /// # Safety
+    ///
+    /// `k` must be less than the number of elements in the window.
+    pub unsafe fn get_back_unchecked(&self, k: usize) -> T {
+        self.storage.get_back_unchecked(k)
+    }
+
+    /// Returns the element `k` steps before the last (newest) element, i.e. `x_{t-k}`.
+    pub fn lag(&self, k: usize) -> Result<T, SlidingWindowError> {
+        self.storage.get_back(k)
+    }
+
+    pub fn arr<const SIZE: usize>(&self) -> Result<[T; SIZE], SlidingWindowError> {
+        match self.warm_up {
+            WarmUp::Partial if !self.storage.filled() => {
+                let len = self.storage.len();
+                if len != SIZE {
+                    return Err(SlidingWindowError::SizeMismatch { expected: len, got: SIZE });
+                }
+
+                let mut iter = self.storage.iter();
+                Ok(core::array::from_fn(|_| iter.next().cloned().expect("window holds SIZE elements")))
+            }
+            _ => self.storage.arr(),
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    pub fn vec(&self) -> Result<Vec<T>, SlidingWindowError> {
+        match self.warm_up {
+            WarmUp::Partial => Ok(self.storage.iter().cloned().collect()),
+            _ => self.storage.vec(),
+        }
+    }
+}
+
+impl<S, T> SlidingWindow<S, T>
+    where
+        T: Clone + Sub<Output = T>,
+        S: Storage<T>,
+{
+    /// Returns the difference `x_t - x_{t-k}` between the last (newest) element
+    /// and the element `k` steps before it.
+    pub fn diff(&self, k: usize) -> Result<T, SlidingWindowError> {
+        Ok(self.storage.get_back(0)? - self.storage.get_back(k)?)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> SlidingWindow<VectorStorage<T>, T>
+    where
+        T: PartialEq + Copy,
+{
+    /// Changes the window size to `size` without losing data.
+    /// Growing keeps all retained history up to the new size, and shrinking drops the oldest elements.
+    /// A padded window is padded again up to the new size.
+    ///
+    /// Fails with `InvalidSize` if `size` is zero, with `InvalidMultiple` if the new capacity overflows,
+    /// and with `AllocationFailed` if it cannot be allocated. The window is left unchanged on failure.
+    pub fn resize(&mut self, size: usize) -> Result<(), SlidingWindowError>
+    {
+        self.storage.resize(size)?;
+
+        if let WarmUp::Padded(value) = self.warm_up {
+            if !self.storage.filled() {
+                let history = self.storage.get_slice().to_vec();
+                let mut storage = VectorStorage::with_rewind(size, self.storage.multiple(), self.storage.rewind());
+                for _ in history.len()..size {
+                    storage.push(value);
+                }
+                for value in history {
+                    storage.push(value);
+                }
+                self.storage = storage;
+            }
+        }
+        Ok(())
     }
 
-    pub fn slice(&self) -> Result<&[T], String> {
-        self.storage.slice()
+    /// Changes the multiple of the window size that is allocated, i.e. how often the window rewinds.
+    ///
+    /// Fails with `InvalidMultiple` if `multiple` is less than two, which would rewind on every push,
+    /// or if the new capacity overflows, and with `AllocationFailed` if it cannot be allocated.
+    /// The window is left unchanged on failure.
+    pub fn set_multiple(&mut self, multiple: usize) -> Result<(), SlidingWindowError>
+    {
+        self.storage.set_multiple(multiple)
     }
 
-    pub fn vec(&self) -> Result<Vec<T>, String> {
-        self.storage.vec()
+    /// Returns the multiple of the window size that is allocated.
+    pub fn multiple(&self) -> usize
+    {
+        self.storage.multiple()
+    }
+
+    /// Returns how the window rewinds once its capacity is exhausted.
+    pub fn rewind(&self) -> Rewind
+    {
+        self.storage.rewind()
+    }
+
+    /// Changes how the window rewinds once its capacity is exhausted.
+    /// The window keeps its elements.
+    pub fn set_rewind(&mut self, rewind: Rewind)
+    {
+        self.storage.set_rewind(rewind);
     }
-}
\ No newline at end of file
+}
+
+impl<T, const SIZE: usize, const CAPACITY: usize> SlidingWindow<ArrayStorage<T, SIZE, CAPACITY>, T>
+    where
+        T: PartialEq + Copy,
+{
+    /// Returns how the window rewinds once its capacity is exhausted.
+    pub fn rewind(&self) -> Rewind
+    {
+        self.storage.rewind()
+    }
+
+    /// Changes how the window rewinds once its capacity is exhausted.
+    /// The window keeps its elements.
+    pub fn set_rewind(&mut self, rewind: Rewind)
+    {
+        self.storage.set_rewind(rewind);
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, const SIZE: usize, const CAPACITY: usize> SlidingWindow<SelectedStorage<T, SIZE, CAPACITY>, T>
+    where
+        T: PartialEq + Copy,
+{
+    /// Returns true if the builder selected the array backend.
+    pub fn is_array(&self) -> bool
+    {
+        self.storage.is_array()
+    }
+
+    /// Returns how the window rewinds once its capacity is exhausted.
+    pub fn rewind(&self) -> Rewind
+    {
+        self.storage.rewind()
+    }
+
+    /// Changes how the window rewinds once its capacity is exhausted.
+    /// The window keeps its elements.
+    pub fn set_rewind(&mut self, rewind: Rewind)
+    {
+        self.storage.set_rewind(rewind);
+    }
+}
+
+impl<S, T> Index<usize> for SlidingWindow<S, T>
+    where
+        S: Storage<T>,
+{
+    type Output = T;
+
+    /// Returns the element at `index`, counted from the first (oldest) element.
+    /// Panics if `index` is out of bounds.
+    fn index(&self, index: usize) -> &Self::Output {
+        match self.storage.get_ref(index) {
+            Some(value) => value,
+            None => panic!("index out of bounds: the len is {} but the index is {}", self.storage.len(), index),
+        }
+    }
+}
+
+impl<'a, S, T> IntoIterator for &'a SlidingWindow<S, T>
+    where
+        S: Storage<T>,
+{
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
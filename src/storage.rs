@@ -2,77 +2,191 @@
  * Copyright (c) 2023. Marvin Hansen <marvin.hansen@gmail.com> All rights reserved.
  */
 
+use core::iter::Rev;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::error::SlidingWindowError;
+use crate::iter::Iter;
+
+/// Backing storage of a sliding window.
+///
+/// The element type is unconstrained, so that owned types such as `String` can be stored.
+/// Methods that return elements by value require `T: Clone`.
+#[allow(clippy::len_without_is_empty)]
 pub trait Storage<T>
-    where
-        T: PartialEq + Copy + Default
 {
     /// Pushes a new element to the beginning of the sliding window.
-    /// If the window is filled, the last element will be dropped.
-    fn push(&mut self, value: T);
+    /// If the window is filled, the last element is evicted and handed back.
+    fn push_evict(&mut self, value: T) -> Option<T>;
     /// Returns the first element in the sliding window
-    fn first(&self) -> Result<T, String>;
+    fn first(&self) -> Result<T, SlidingWindowError> where T: Clone;
     /// Returns the last element in the sliding window
-    fn last(&self) -> Result<T, String>;
+    fn last(&self) -> Result<T, SlidingWindowError> where T: Clone;
     /// Returns tail cursor
     fn tail(&self) -> usize;
     /// Returns size
     fn size(&self) -> usize;
-    /// Returns sliding window as slice
+    /// Returns sliding window as slice.
+    /// Storages that wrap around return the part up to the wrap only,
+    /// so generic code reads the window through `as_slices`, `iter` or `slice`.
     fn get_slice(&self) -> &[T];
 
     //
     // Default implementations. Override as required.
     //
 
+    /// Returns the sliding window as two slices, from the first (oldest) to the last (newest) element.
+    /// Contiguous storages return the whole window as the first slice and an empty second slice.
+    /// Storages that wrap around override this together with `make_contiguous`.
+    fn as_slices(&self) -> (&[T], &[T]) {
+        (self.get_slice(), &[])
+    }
+
+    /// Rearranges the storage so that the whole window is contiguous and returns it as a slice.
+    fn make_contiguous(&mut self) -> &[T] {
+        self.get_slice()
+    }
+
+    /// Pushes a new element to the beginning of the sliding window.
+    /// If the window is filled, the last element will be dropped.
+    fn push(&mut self, value: T) {
+        self.push_evict(value);
+    }
+
+    /// Returns a reference to the first element in the sliding window.
+    fn first_ref(&self) -> Result<&T, SlidingWindowError> {
+        let (front, back) = self.as_slices();
+        front.first().or(back.first()).ok_or(SlidingWindowError::Empty)
+    }
+
+    /// Returns a reference to the last element in the sliding window.
+    fn last_ref(&self) -> Result<&T, SlidingWindowError> {
+        if !self.filled() {
+            return Err(SlidingWindowError::NotFilled { len: self.len(), size: self.size() });
+        }
+
+        let (front, back) = self.as_slices();
+        back.last().or(front.last()).ok_or(SlidingWindowError::Empty)
+    }
+
+    /// Returns the number of elements in the window.
+    fn len(&self) -> usize {
+        let (front, back) = self.as_slices();
+        front.len() + back.len()
+    }
+
     /// Returns true if the window is empty.
     fn empty(&self) -> bool {
-        return if self.tail() == 0 {
-            true
-        } else {
-            false
-        };
+        self.tail() == 0
     }
 
     /// Returns true if the window is filled.
     fn filled(&self) -> bool
     {
-        return if self.tail() < self.size() {
-            false
+        self.tail() >= self.size()
+    }
+
+    /// Returns a reference to the element at `index`, counted from the first (oldest) element.
+    fn get_ref(&self, index: usize) -> Option<&T> {
+        let (front, back) = self.as_slices();
+        if index < front.len() {
+            front.get(index)
         } else {
-            true
-        };
+            back.get(index - front.len())
+        }
+    }
+
+    /// Returns the element at `index`, counted from the first (oldest) element.
+    fn get(&self, index: usize) -> Result<T, SlidingWindowError> where T: Clone {
+        match self.get_ref(index) {
+            Some(value) => Ok(value.clone()),
+            None => Err(SlidingWindowError::OutOfBounds { index, len: self.len() }),
+        }
+    }
+
+    /// Returns the element at `index`, counted from the first (oldest) element, without bounds checks.
+    ///
+    /// # Safety
+    ///
+    /// `index` must be less than the number of elements in the window.
+    unsafe fn get_unchecked(&self, index: usize) -> T where T: Clone {
+        let (front, back) = self.as_slices();
+        if index < front.len() {
+            front.get_unchecked(index).clone()
+        } else {
+            back.get_unchecked(index - front.len()).clone()
+        }
+    }
+
+    /// Returns the element `k` positions before the last (newest) element,
+    /// i.e. `get_back(0)` is the newest element.
+    fn get_back(&self, k: usize) -> Result<T, SlidingWindowError> where T: Clone {
+        let len = self.len();
+        if k < len {
+            self.get(len - 1 - k)
+        } else {
+            Err(SlidingWindowError::OutOfBounds { index: k, len })
+        }
+    }
+
+    /// Returns the element `k` positions before the last (newest) element, without bounds checks.
+    ///
+    /// # Safety
+    ///
+    /// `k` must be less than the number of elements in the window.
+    unsafe fn get_back_unchecked(&self, k: usize) -> T where T: Clone {
+        self.get_unchecked(self.len() - 1 - k)
+    }
+
+    /// Returns an iterator from the first (oldest) to the last (newest) element.
+    /// Works on partially filled windows.
+    fn iter(&self) -> Iter<'_, T> {
+        let (front, back) = self.as_slices();
+        Iter::new(front, back)
+    }
+
+    /// Returns an iterator from the last (newest) to the first (oldest) element.
+    /// Works on partially filled windows.
+    fn iter_newest_first(&self) -> Rev<Iter<'_, T>> {
+        self.iter().rev()
     }
 
     /// Returns the sliding window as a fixed size static array.
-    fn arr<const S: usize>(&self) -> Result<[T; S], String>{
+    /// Fails with `SizeMismatch` if `S` differs from the window size.
+    fn arr<const S: usize>(&self) -> Result<[T; S], SlidingWindowError> where T: Clone {
         if !self.filled() {
-            return Err(format!("Sliding window is not yet filled. Add some elements to the array first"))
+            return Err(SlidingWindowError::NotFilled { len: self.len(), size: self.size() });
         }
 
-        let mut arr: [T; S] = [T::default(); S];
-        let slice = self.get_slice();
-        for i in 0..self.size() {
-            arr[i] = slice[i];
+        if S != self.size() {
+            return Err(SlidingWindowError::SizeMismatch { expected: self.size(), got: S });
         }
 
-        Ok(arr)
+        let mut iter = self.iter();
+        Ok(core::array::from_fn(|_| iter.next().cloned().expect("window holds S elements")))
     }
 
     /// Returns the sliding window as a slice.
-    fn slice(&self) -> Result<&[T], String> {
-        return if !self.filled() {
-            Err(format!("Sliding window is not yet filled. Add some elements to the array first"))
-        } else {
-            Ok(self.get_slice())
+    /// Fails with `NotContiguous` if the window is split into two slices, see `make_contiguous`.
+    fn slice(&self) -> Result<&[T], SlidingWindowError> {
+        if !self.filled() {
+            return Err(SlidingWindowError::NotFilled { len: self.len(), size: self.size() });
+        }
+
+        match self.as_slices() {
+            (front, []) => Ok(front),
+            _ => Err(SlidingWindowError::NotContiguous),
         }
     }
 
     /// Returns the sliding window as a vector.
-    fn vec(&self) -> Result<Vec<T>, String> {
-        return if !self.filled() {
-            Err(format!("Sliding window is not yet filled. Add some elements to the array first"))
+    #[cfg(feature = "alloc")]
+    fn vec(&self) -> Result<Vec<T>, SlidingWindowError> where T: Clone {
+        if !self.filled() {
+            Err(SlidingWindowError::NotFilled { len: self.len(), size: self.size() })
         } else {
-            Ok(self.get_slice().to_vec())
+            Ok(self.iter().cloned().collect())
         }
     }
-}
\ No newline at end of file
+}
@@ -2,56 +2,120 @@
  * Copyright (c) 2023. Marvin Hansen <marvin.hansen@gmail.com> All rights reserved.
  */
 
+use core::mem::MaybeUninit;
+use core::slice;
+
+use crate::error::SlidingWindowError;
+use crate::rewind::Rewind;
 use crate::storage::Storage;
 
 pub struct ArrayStorage<T, const SIZE: usize, const CAPACITY: usize>
     where
-        T: PartialEq + Copy + Default,
+        T: PartialEq + Copy,
         [T; CAPACITY]: Sized,
 {
-    arr: [T; CAPACITY],
+    // Slots are written before they are read, so the array needs no initialization,
+    // which keeps `new` a const fn.
+    arr: [MaybeUninit<T>; CAPACITY],
     size: usize,
     head: usize,
     tail: usize,
+    rewind: Rewind,
+    // tail position from which a push also writes its element to the front
+    copy_from: usize,
 }
 
 impl<T, const SIZE: usize, const CAPACITY: usize> ArrayStorage<T, SIZE, CAPACITY>
     where
-        T: PartialEq + Copy + Default,
+        T: PartialEq + Copy,
         [T; CAPACITY]: Sized,
 {
-    pub fn new() -> Self
+    pub const fn new() -> Self
+    {
+        Self::with_rewind(Rewind::Full)
+    }
+
+    pub const fn with_rewind(rewind: Rewind) -> Self
     {
         Self {
-            arr: [T::default(); CAPACITY],
+            arr: [MaybeUninit::uninit(); CAPACITY],
             size: SIZE,
             head: 0,
             tail: 0,
+            rewind,
+            copy_from: rewind.copy_from(SIZE, CAPACITY),
         }
     }
+
+    /// Returns how the array rewinds once the tail hits the capacity.
+    pub const fn rewind(&self) -> Rewind
+    {
+        self.rewind
+    }
+
+    /// Changes how the array rewinds once the tail hits the capacity.
+    /// The window keeps its elements.
+    pub fn set_rewind(&mut self, rewind: Rewind)
+    {
+        self.rewind = rewind;
+        self.copy_from = rewind.copy_from(SIZE, CAPACITY);
+
+        // catch up on the elements that were pushed without being written to the front
+        if self.tail > self.copy_from
+        {
+            self.arr.copy_within(self.copy_from..self.tail, 0);
+        }
+    }
+
+    /// Returns the total number of elements the array can hold.
+    pub const fn capacity(&self) -> usize
+    {
+        CAPACITY
+    }
+}
+
+impl<T, const SIZE: usize, const CAPACITY: usize> Default for ArrayStorage<T, SIZE, CAPACITY>
+    where
+        T: PartialEq + Copy,
+        [T; CAPACITY]: Sized,
+{
+    fn default() -> Self
+    {
+        Self::new()
+    }
 }
 
 
 impl<T, const SIZE: usize, const CAPACITY: usize> Storage<T> for ArrayStorage<T, SIZE, CAPACITY>
     where
-        T: PartialEq + Copy + Default,
+        T: PartialEq + Copy,
         [T; SIZE]: Sized,
 {
     fn push(&mut self, value: T) {
         // if the array is full, rewind
         if self.tail > 0 && self.tail == self.arr.len()
         {
-            // rewind
-            for i in 0..self.size - 1
+            // rewind the newest size - 1 elements to the front,
+            // unless the incremental rewind has written them there already
+            if self.copy_from == CAPACITY
             {
-                self.arr[i] = self.arr[self.head + i];
+                self.arr.copy_within(self.tail + 1 - self.size..self.tail, 0);
             }
             self.head = 0;
-            self.tail = self.size;
+            self.tail = self.size - 1;
         }
 
         // push the value
-        self.arr[self.tail] = value;
+        self.arr[self.tail] = MaybeUninit::new(value);
+
+        // incremental rewind: also write the value to its position after the next rewind
+        if self.tail >= self.copy_from
+        {
+            self.arr[self.tail - self.copy_from] = MaybeUninit::new(value);
+        }
+
+        //increase tail cursor to next position
+        self.tail += 1;
 
         // check if the window is full,
         if self.tail - self.head > self.size
@@ -59,25 +123,29 @@ impl<T, const SIZE: usize, const CAPACITY: usize> Storage<T> for ArrayStorage<T,
             // move head cursor one position forward
             self.head += 1;
         }
-
-        //increase tail cursor to next position
-        self.tail += 1;
     }
 
-    fn first(&self) -> Result<T, String> {
-        return if self.tail != 0 {
-            Ok(self.arr[self.head])
+    fn push_evict(&mut self, value: T) -> Option<T> {
+        let evicted = if self.filled() {
+            self.get_slice().first().copied()
         } else {
-            Err(format!("Array is empty. Add some elements to the array first"))
+            None
         };
+
+        self.push(value);
+        evicted
+    }
+
+    fn first(&self) -> Result<T, SlidingWindowError> {
+        self.get_slice().first().copied().ok_or(SlidingWindowError::Empty)
     }
 
-    fn last(&self) -> Result<T, String> {
-        return if self.filled() {
-            Ok(self.arr[self.tail - 1])
+    fn last(&self) -> Result<T, SlidingWindowError> {
+        if self.filled() {
+            Ok(self.get_slice()[self.tail - self.head - 1])
         } else {
-            Err(format!("Array is not yet filled. Add some elements to the array first"))
-        };
+            Err(SlidingWindowError::NotFilled { len: self.tail, size: self.size })
+        }
     }
 
     fn tail(&self) -> usize {
@@ -88,13 +156,10 @@ impl<T, const SIZE: usize, const CAPACITY: usize> Storage<T> for ArrayStorage<T,
         self.size
     }
 
-    fn get_slice(&self) -> &[T] {
-        if self.tail > self.size
-        {
-            // Adjust offset in case the window is larger than the slice.
-            &self.arr[self.head + 1..self.tail]
-        } else {
-            &self.arr[self.head..self.tail]
-        }
+    fn get_slice(&self) -> &[T]
+    {
+        let window = &self.arr[self.head..self.tail];
+        // Safety: slots within head..tail have been written.
+        unsafe { slice::from_raw_parts(window.as_ptr() as *const T, window.len()) }
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,69 @@
+/*
+ * Copyright (c) 2023. Marvin Hansen <marvin.hansen@gmail.com> All rights reserved.
+ */
+
+use core::mem::MaybeUninit;
+
+use crate::error::SlidingWindowError;
+use crate::storage::Storage;
+use crate::uninit_buffer::UninitBuffer;
+
+/// Array backed storage that leaves unused slots uninitialized.
+///
+/// Unlike `ArrayStorage`, it neither requires `T: Default` nor an initialization pass
+/// over the whole capacity on construction. Slots are initialized as they are written,
+/// and elements leaving the window are dropped on push or handed back by `push_evict`.
+pub struct UninitArrayStorage<T, const SIZE: usize, const CAPACITY: usize>
+{
+    buf: UninitBuffer<[MaybeUninit<T>; CAPACITY], T>,
+}
+
+impl<T, const SIZE: usize, const CAPACITY: usize> UninitArrayStorage<T, SIZE, CAPACITY>
+{
+    pub const fn new() -> Self
+    {
+        Self {
+            // Safety: an array of MaybeUninit does not require initialization.
+            buf: UninitBuffer::new(unsafe { MaybeUninit::uninit().assume_init() }, SIZE),
+        }
+    }
+}
+
+impl<T, const SIZE: usize, const CAPACITY: usize> Default for UninitArrayStorage<T, SIZE, CAPACITY>
+{
+    fn default() -> Self
+    {
+        Self::new()
+    }
+}
+
+impl<T, const SIZE: usize, const CAPACITY: usize> Storage<T> for UninitArrayStorage<T, SIZE, CAPACITY>
+{
+    fn push_evict(&mut self, value: T) -> Option<T> {
+        self.buf.push_evict(value)
+    }
+
+    fn first(&self) -> Result<T, SlidingWindowError> where T: Clone {
+        self.first_ref().cloned()
+    }
+
+    fn last(&self) -> Result<T, SlidingWindowError> where T: Clone {
+        self.last_ref().cloned()
+    }
+
+    #[inline(always)]
+    fn tail(&self) -> usize {
+        self.buf.tail()
+    }
+
+    #[inline(always)]
+    fn size(&self) -> usize {
+        self.buf.size()
+    }
+
+    #[inline(always)]
+    fn get_slice(&self) -> &[T]
+    {
+        self.buf.get_slice()
+    }
+}
@@ -0,0 +1,99 @@
+/*
+ * Copyright (c) 2023. Marvin Hansen <marvin.hansen@gmail.com> All rights reserved.
+ */
+
+use alloc::vec::Vec;
+use core::mem::MaybeUninit;
+use core::slice;
+
+use crate::error::SlidingWindowError;
+use crate::storage::Storage;
+
+/// Mirrored storage that writes each element twice into a buffer of `2 * size` elements.
+///
+/// The element at position `i` of the ring is also written at `i + size`, so that the
+/// window is always the contiguous slice starting at the oldest element.
+/// Every push writes two slots and never rewinds, which makes push O(1) in the worst case
+/// without the latency spikes of the rewinding implementations, at the cost of one extra write.
+pub struct MirroredStorage<T>
+    where T: PartialEq + Copy
+{
+    buf: Vec<MaybeUninit<T>>,
+    size: usize,
+    // next write position in the lower half, which is the oldest element once filled
+    head: usize,
+    len: usize,
+}
+
+impl<T> MirroredStorage<T>
+    where
+        T: PartialEq + Copy,
+{
+    pub fn new(size: usize) -> Self
+    {
+        let mut buf = Vec::with_capacity(2 * size);
+        buf.resize(2 * size, MaybeUninit::uninit());
+
+        Self {
+            buf,
+            size,
+            head: 0,
+            len: 0,
+        }
+    }
+}
+
+impl<T> Storage<T> for MirroredStorage<T>
+    where
+        T: PartialEq + Copy,
+{
+    fn push_evict(&mut self, value: T) -> Option<T> {
+        let evicted = if self.len == self.size
+        {
+            // Safety: once filled, every slot of the buffer has been written.
+            Some(unsafe { self.buf[self.head].assume_init() })
+        } else {
+            self.len += 1;
+            None
+        };
+
+        // write the value and its mirror
+        self.buf[self.head] = MaybeUninit::new(value);
+        self.buf[self.head + self.size] = MaybeUninit::new(value);
+
+        self.head += 1;
+        if self.head == self.size {
+            self.head = 0;
+        }
+
+        evicted
+    }
+
+    fn first(&self) -> Result<T, SlidingWindowError> {
+        self.first_ref().copied()
+    }
+
+    fn last(&self) -> Result<T, SlidingWindowError> {
+        self.last_ref().copied()
+    }
+
+    #[inline(always)]
+    fn tail(&self) -> usize {
+        self.len
+    }
+
+    #[inline(always)]
+    fn size(&self) -> usize {
+        self.size
+    }
+
+    #[inline(always)]
+    fn get_slice(&self) -> &[T]
+    {
+        // until the window is filled, the elements are at the front of the lower half
+        let start = if self.len < self.size { 0 } else { self.head };
+        let window = &self.buf[start..start + self.len];
+        // Safety: slots within start..start + len have been written, either directly or as mirror.
+        unsafe { slice::from_raw_parts(window.as_ptr() as *const T, window.len()) }
+    }
+}
@@ -0,0 +1,268 @@
+/*
+ * Copyright (c) 2023. Marvin Hansen <marvin.hansen@gmail.com> All rights reserved.
+ */
+
+use alloc::vec::Vec;
+use core::mem::{self, MaybeUninit};
+use core::ptr;
+use core::slice;
+use std::io;
+
+use crate::error::SlidingWindowError;
+use crate::storage::Storage;
+
+/// Virtual memory mirrored ring buffer, available on Linux with the `mmap` feature.
+///
+/// The ring lives in a memfd region that is mapped twice, back to back, into the address space.
+/// An element written at position `i` of the ring is therefore also visible at `i + capacity`,
+/// so the window is always one contiguous slice without any copy, and every push is a single O(1) write.
+/// Mappings are page granular, so the capacity of the ring is the window size rounded up to whole pages
+/// and may be larger than the window size.
+///
+/// If the mapping cannot be created, `new` falls back to a heap buffer of twice the window size
+/// that writes each element twice, like `MirroredStorage`. `is_mapped` tells which one is used.
+pub struct MmapStorage<T>
+    where T: PartialEq + Copy
+{
+    ptr: *mut T,
+    capacity: usize,
+    size: usize,
+    // next write position of the ring
+    head: usize,
+    // position of the first (oldest) element of the ring
+    first: usize,
+    len: usize,
+    // keeps the double mapping alive, None for the heap fallback
+    mapping: Option<Mapping>,
+    // backs the heap fallback, empty for the double mapping
+    heap: Vec<MaybeUninit<T>>,
+}
+
+// Safety: the storage exclusively owns the memory behind the raw pointer.
+unsafe impl<T> Send for MmapStorage<T> where T: PartialEq + Copy + Send {}
+
+// Safety: shared references only read from the memory behind the raw pointer.
+unsafe impl<T> Sync for MmapStorage<T> where T: PartialEq + Copy + Sync {}
+
+impl<T> MmapStorage<T>
+    where
+        T: PartialEq + Copy,
+{
+    /// Creates a double mapped ring buffer for a window of `size` elements,
+    /// or falls back to a heap buffer if the mapping fails.
+    /// Panics if `size` is zero.
+    pub fn new(size: usize) -> Self
+    {
+        Self::try_new(size).unwrap_or_else(|_| Self::new_unmapped(size))
+    }
+
+    /// Creates a double mapped ring buffer for a window of `size` elements.
+    /// Fails with the OS error of the first syscall that failed, or with `InvalidInput`
+    /// for a zero size, a zero sized element type, or a capacity that overflows.
+    pub fn try_new(size: usize) -> io::Result<Self>
+    {
+        let element = mem::size_of::<T>();
+        if size == 0 || element == 0 {
+            return Err(io::ErrorKind::InvalidInput.into());
+        }
+
+        let bytes = Mapping::round_to_pages(size, element).ok_or(io::ErrorKind::InvalidInput)?;
+        let mapping = Mapping::new(bytes)?;
+
+        Ok(Self {
+            ptr: mapping.ptr as *mut T,
+            capacity: bytes / element,
+            size,
+            head: 0,
+            first: 0,
+            len: 0,
+            mapping: Some(mapping),
+            heap: Vec::new(),
+        })
+    }
+
+    /// Creates the heap buffer that `new` falls back to, without trying to map memory.
+    /// Panics if `size` is zero.
+    pub fn new_unmapped(size: usize) -> Self
+    {
+        assert!(size > 0);
+
+        let mut heap = Vec::with_capacity(2 * size);
+        heap.resize(2 * size, MaybeUninit::uninit());
+
+        Self {
+            ptr: heap.as_mut_ptr() as *mut T,
+            capacity: size,
+            size,
+            head: 0,
+            first: 0,
+            len: 0,
+            mapping: None,
+            heap,
+        }
+    }
+
+    /// Returns true if the ring buffer is double mapped, false for the heap fallback.
+    pub fn is_mapped(&self) -> bool
+    {
+        self.mapping.is_some()
+    }
+
+    /// Returns the number of elements of the ring, which is at least the window size.
+    pub fn capacity(&self) -> usize
+    {
+        self.capacity
+    }
+}
+
+impl<T> Storage<T> for MmapStorage<T>
+    where
+        T: PartialEq + Copy,
+{
+    fn push_evict(&mut self, value: T) -> Option<T> {
+        let evicted = if self.len == self.size
+        {
+            // Safety: the first element of a filled window has been written and lies within the ring.
+            let evicted = unsafe { ptr::read(self.ptr.add(self.first)) };
+            self.first += 1;
+            if self.first == self.capacity {
+                self.first = 0;
+            }
+            Some(evicted)
+        } else {
+            self.len += 1;
+            None
+        };
+
+        // Safety: head is within the ring, and the heap fallback holds twice the ring.
+        unsafe {
+            ptr::write(self.ptr.add(self.head), value);
+            if !self.heap.is_empty() {
+                ptr::write(self.ptr.add(self.head + self.capacity), value);
+            }
+        }
+
+        self.head += 1;
+        if self.head == self.capacity {
+            self.head = 0;
+        }
+
+        evicted
+    }
+
+    fn first(&self) -> Result<T, SlidingWindowError> {
+        self.first_ref().copied()
+    }
+
+    fn last(&self) -> Result<T, SlidingWindowError> {
+        self.last_ref().copied()
+    }
+
+    #[inline(always)]
+    fn tail(&self) -> usize {
+        self.len
+    }
+
+    #[inline(always)]
+    fn size(&self) -> usize {
+        self.size
+    }
+
+    #[inline(always)]
+    fn get_slice(&self) -> &[T]
+    {
+        // Safety: the window starts within the ring and is at most one ring long,
+        // so it ends within the mirror, which aliases the written elements of the ring.
+        unsafe { slice::from_raw_parts(self.ptr.add(self.first), self.len) }
+    }
+}
+
+/// A memfd region of `len` bytes that is mapped twice, back to back.
+struct Mapping {
+    ptr: *mut u8,
+    len: usize,
+}
+
+impl Mapping
+{
+    /// Rounds `size` elements of `element` bytes up to a whole number of pages
+    /// that also holds a whole number of elements.
+    fn round_to_pages(size: usize, element: usize) -> Option<usize>
+    {
+        // Safety: sysconf has no preconditions.
+        let page = unsafe { libc::sysconf(libc::_SC_PAGESIZE) };
+        let page = if page > 0 { page as usize } else { 4096 };
+
+        let mut a = page;
+        let mut b = element;
+        while b != 0 {
+            (a, b) = (b, a % b);
+        }
+        let step = (page / a).checked_mul(element)?;
+
+        let bytes = size.checked_mul(element)?;
+        let bytes = bytes.checked_add(step - 1)? / step * step;
+        // the double mapping must fit into the address space
+        bytes.checked_mul(2).filter(|&total| total <= isize::MAX as usize)?;
+        Some(bytes)
+    }
+
+    fn new(len: usize) -> io::Result<Self>
+    {
+        // Safety: the name is a nul terminated string.
+        let fd = unsafe { libc::memfd_create(b"sliding_window\0".as_ptr() as *const libc::c_char, libc::MFD_CLOEXEC) };
+        if fd < 0 {
+            return Err(io::Error::last_os_error());
+        }
+
+        let mapping = Self::map(fd, len);
+        // Safety: fd is an open file descriptor. The mappings keep the memfd alive after it is closed.
+        unsafe { libc::close(fd) };
+        mapping
+    }
+
+    fn map(fd: libc::c_int, len: usize) -> io::Result<Self>
+    {
+        // Safety: ftruncate on an open memfd.
+        if unsafe { libc::ftruncate(fd, len as libc::off_t) } != 0 {
+            return Err(io::Error::last_os_error());
+        }
+
+        // Safety: reserves twice the length of address space, which is not accessible yet.
+        let reserved = unsafe { libc::mmap(ptr::null_mut(), 2 * len, libc::PROT_NONE, libc::MAP_PRIVATE | libc::MAP_ANONYMOUS, -1, 0) };
+        if reserved == libc::MAP_FAILED {
+            return Err(io::Error::last_os_error());
+        }
+
+        // From here on, dropping the mapping unmaps the whole reservation.
+        let mapping = Self { ptr: reserved as *mut u8, len };
+
+        for half in 0..2 {
+            // Safety: replaces one half of the reservation with a shared mapping of the memfd.
+            let addr = unsafe {
+                libc::mmap(
+                    mapping.ptr.add(half * len) as *mut libc::c_void,
+                    len,
+                    libc::PROT_READ | libc::PROT_WRITE,
+                    libc::MAP_SHARED | libc::MAP_FIXED,
+                    fd,
+                    0,
+                )
+            };
+            if addr == libc::MAP_FAILED {
+                return Err(io::Error::last_os_error());
+            }
+        }
+
+        Ok(mapping)
+    }
+}
+
+impl Drop for Mapping
+{
+    fn drop(&mut self)
+    {
+        // Safety: unmaps the reservation, which covers both halves, exactly once.
+        unsafe { libc::munmap(self.ptr as *mut libc::c_void, 2 * self.len) };
+    }
+}
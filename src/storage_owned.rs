@@ -0,0 +1,62 @@
+/*
+ * Copyright (c) 2023. Marvin Hansen <marvin.hansen@gmail.com> All rights reserved.
+ */
+
+use alloc::boxed::Box;
+use core::mem::MaybeUninit;
+
+use crate::error::SlidingWindowError;
+use crate::storage::Storage;
+use crate::uninit_buffer::UninitBuffer;
+
+/// Over-allocated storage for element types that are not `Copy`, such as `String` or `Arc`.
+///
+/// Only the slots of the current window are initialized. An element leaving the window
+/// is moved out immediately, i.e. dropped by `push` or handed back by `push_evict`.
+/// The rewind moves the remaining elements to the front of the buffer without cloning them.
+pub struct OwnedStorage<T>
+{
+    buf: UninitBuffer<Box<[MaybeUninit<T>]>, T>,
+}
+
+impl<T> OwnedStorage<T>
+{
+    pub fn new(size: usize, multiple: usize) -> Self
+    {
+        let capacity = size * multiple;
+        Self {
+            buf: UninitBuffer::new((0..capacity).map(|_| MaybeUninit::uninit()).collect(), size),
+        }
+    }
+}
+
+impl<T> Storage<T> for OwnedStorage<T>
+{
+    fn push_evict(&mut self, value: T) -> Option<T> {
+        self.buf.push_evict(value)
+    }
+
+    fn first(&self) -> Result<T, SlidingWindowError> where T: Clone {
+        self.first_ref().cloned()
+    }
+
+    fn last(&self) -> Result<T, SlidingWindowError> where T: Clone {
+        self.last_ref().cloned()
+    }
+
+    #[inline(always)]
+    fn tail(&self) -> usize {
+        self.buf.tail()
+    }
+
+    #[inline(always)]
+    fn size(&self) -> usize {
+        self.buf.size()
+    }
+
+    #[inline(always)]
+    fn get_slice(&self) -> &[T]
+    {
+        self.buf.get_slice()
+    }
+}
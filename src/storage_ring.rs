@@ -0,0 +1,95 @@
+/*
+ * Copyright (c) 2023. Marvin Hansen <marvin.hansen@gmail.com> All rights reserved.
+ */
+
+use alloc::vec::Vec;
+use core::mem;
+
+use crate::error::SlidingWindowError;
+use crate::storage::Storage;
+
+/// Ring buffer storage that allocates exactly `size` elements.
+///
+/// Once the window is filled, each push overwrites the oldest element in place,
+/// so push is O(1) in the worst case and never rewinds. In exchange, the window
+/// wraps around the end of the buffer and is generally made of two slices,
+/// see `as_slices`. `make_contiguous` rotates the buffer when a single slice is required.
+pub struct RingStorage<T>
+    where T: PartialEq + Copy
+{
+    vec: Vec<T>,
+    size: usize,
+    // index of the first (oldest) element once the window is filled
+    head: usize,
+}
+
+impl<T> RingStorage<T>
+    where
+        T: PartialEq + Copy,
+{
+    pub fn new(size: usize) -> Self
+    {
+        Self {
+            vec: Vec::with_capacity(size),
+            size,
+            head: 0,
+        }
+    }
+}
+
+impl<T> Storage<T> for RingStorage<T>
+    where
+        T: PartialEq + Copy,
+{
+    fn push_evict(&mut self, value: T) -> Option<T> {
+        if self.vec.len() < self.size {
+            self.vec.push(value);
+            return None;
+        }
+
+        // overwrite the oldest element and advance the head
+        let evicted = mem::replace(&mut self.vec[self.head], value);
+        self.head += 1;
+        if self.head == self.size {
+            self.head = 0;
+        }
+        Some(evicted)
+    }
+
+    fn first(&self) -> Result<T, SlidingWindowError> {
+        self.first_ref().copied()
+    }
+
+    fn last(&self) -> Result<T, SlidingWindowError> {
+        self.last_ref().copied()
+    }
+
+    #[inline(always)]
+    fn tail(&self) -> usize {
+        self.vec.len()
+    }
+
+    #[inline(always)]
+    fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Returns the window from the first (oldest) element up to the end of the buffer,
+    /// which is the whole window only if it does not wrap around, see `as_slices`.
+    #[inline(always)]
+    fn get_slice(&self) -> &[T]
+    {
+        self.as_slices().0
+    }
+
+    #[inline(always)]
+    fn as_slices(&self) -> (&[T], &[T]) {
+        (&self.vec[self.head..], &self.vec[..self.head])
+    }
+
+    fn make_contiguous(&mut self) -> &[T] {
+        self.vec.rotate_left(self.head);
+        self.head = 0;
+        &self.vec
+    }
+}
@@ -0,0 +1,110 @@
+/*
+ * Copyright (c) 2023. Marvin Hansen <marvin.hansen@gmail.com> All rights reserved.
+ */
+
+use crate::error::SlidingWindowError;
+use crate::rewind::Rewind;
+use crate::storage::Storage;
+use crate::storage_array::ArrayStorage;
+use crate::storage_vec::VectorStorage;
+
+/// Storage that the builder selects from the configuration, see `SlidingWindowBuilder::build_selected`.
+///
+/// Holds an array of `CAPACITY` elements if the configured size fits the const generic `SIZE`,
+/// and a vector of `size * multiple` elements otherwise. Both backends behave the same,
+/// so the window can be used without knowing which one was selected.
+pub enum SelectedStorage<T, const SIZE: usize, const CAPACITY: usize>
+    where
+        T: PartialEq + Copy,
+{
+    Array(ArrayStorage<T, SIZE, CAPACITY>),
+    Vector(VectorStorage<T>),
+}
+
+impl<T, const SIZE: usize, const CAPACITY: usize> SelectedStorage<T, SIZE, CAPACITY>
+    where
+        T: PartialEq + Copy,
+{
+    /// Returns true if the array backend was selected.
+    pub fn is_array(&self) -> bool
+    {
+        matches!(self, Self::Array(_))
+    }
+
+    /// Returns how the storage rewinds once the tail hits the capacity.
+    pub fn rewind(&self) -> Rewind
+    {
+        match self {
+            Self::Array(storage) => storage.rewind(),
+            Self::Vector(storage) => storage.rewind(),
+        }
+    }
+
+    /// Changes how the storage rewinds once the tail hits the capacity.
+    /// The window keeps its elements.
+    pub fn set_rewind(&mut self, rewind: Rewind)
+    {
+        match self {
+            Self::Array(storage) => storage.set_rewind(rewind),
+            Self::Vector(storage) => storage.set_rewind(rewind),
+        }
+    }
+}
+
+impl<T, const SIZE: usize, const CAPACITY: usize> Storage<T> for SelectedStorage<T, SIZE, CAPACITY>
+    where
+        T: PartialEq + Copy,
+{
+    fn push(&mut self, value: T) {
+        match self {
+            Self::Array(storage) => storage.push(value),
+            Self::Vector(storage) => storage.push(value),
+        }
+    }
+
+    fn push_evict(&mut self, value: T) -> Option<T> {
+        match self {
+            Self::Array(storage) => storage.push_evict(value),
+            Self::Vector(storage) => storage.push_evict(value),
+        }
+    }
+
+    fn first(&self) -> Result<T, SlidingWindowError> {
+        match self {
+            Self::Array(storage) => storage.first(),
+            Self::Vector(storage) => storage.first(),
+        }
+    }
+
+    fn last(&self) -> Result<T, SlidingWindowError> {
+        match self {
+            Self::Array(storage) => storage.last(),
+            Self::Vector(storage) => storage.last(),
+        }
+    }
+
+    #[inline(always)]
+    fn tail(&self) -> usize {
+        match self {
+            Self::Array(storage) => storage.tail(),
+            Self::Vector(storage) => storage.tail(),
+        }
+    }
+
+    #[inline(always)]
+    fn size(&self) -> usize {
+        match self {
+            Self::Array(storage) => storage.size(),
+            Self::Vector(storage) => storage.size(),
+        }
+    }
+
+    #[inline(always)]
+    fn get_slice(&self) -> &[T]
+    {
+        match self {
+            Self::Array(storage) => storage.get_slice(),
+            Self::Vector(storage) => storage.get_slice(),
+        }
+    }
+}
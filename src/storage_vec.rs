@@ -2,6 +2,11 @@
  * Copyright (c) 2023. Marvin Hansen <marvin.hansen@gmail.com> All rights reserved.
  */
 
+use alloc::vec::Vec;
+use core::alloc::Layout;
+
+use crate::error::SlidingWindowError;
+use crate::rewind::Rewind;
 use crate::storage::{ Storage};
 
 pub struct VectorStorage<T>
@@ -9,71 +14,228 @@ pub struct VectorStorage<T>
 {
     vec: Vec<T>,
     size: usize,
+    multiple: usize,
     head: usize,
     tail: usize,
+    rewind: Rewind,
+    // tail position from which a push also writes its element to the front
+    copy_from: usize,
+    // number of slots at the front that the incremental rewind has overwritten since the last rewind,
+    // which no longer hold evicted elements, even if the rewind mode has changed since
+    overwritten: usize,
 }
 
 impl<T> VectorStorage<T>
     where
-        T: PartialEq + Copy + Default ,
+        T: PartialEq + Copy,
 {
     pub fn new(size: usize, multiple: usize) -> Self
     {
-        let capacity = size * multiple;
+        Self::with_rewind(size, multiple, Rewind::Full)
+    }
+
+    pub fn with_rewind(size: usize, multiple: usize, rewind: Rewind) -> Self
+    {
+        Self::from_vec(Vec::with_capacity(size * multiple), size, multiple, rewind)
+    }
+
+    /// Validates the configuration and creates a vector storage like `with_rewind`,
+    /// but returns an error instead of panicking or aborting on a bad configuration.
+    ///
+    /// Fails with `InvalidSize` if `size` is zero, with `InvalidMultiple` if `multiple` is less than two
+    /// or the capacity `size * multiple` overflows, in elements or in bytes,
+    /// and with `AllocationFailed` if the capacity cannot be allocated.
+    pub fn try_with_rewind(size: usize, multiple: usize, rewind: Rewind) -> Result<Self, SlidingWindowError>
+    {
+        let capacity = checked_capacity::<T>(size, multiple)?;
+        let mut vec = Vec::new();
+        vec.try_reserve_exact(capacity).map_err(|_| SlidingWindowError::AllocationFailed { capacity })?;
+        Ok(Self::from_vec(vec, size, multiple, rewind))
+    }
+
+    fn from_vec(vec: Vec<T>, size: usize, multiple: usize, rewind: Rewind) -> Self
+    {
         Self {
-            vec: Vec::with_capacity(capacity),
+            copy_from: rewind.copy_from(size, vec.capacity()),
+            vec,
             size,
+            multiple,
             head: 0,
             tail: 0,
+            rewind,
+            overwritten: 0,
         }
     }
+
+    /// Returns how the vector rewinds once the tail hits the capacity.
+    pub fn rewind(&self) -> Rewind
+    {
+        self.rewind
+    }
+
+    /// Changes how the vector rewinds once the tail hits the capacity.
+    /// The window keeps its elements.
+    pub fn set_rewind(&mut self, rewind: Rewind)
+    {
+        self.rewind = rewind;
+        self.copy_from = rewind.copy_from(self.size, self.vec.capacity());
+
+        // catch up on the elements that were pushed without being written to the front
+        if self.tail > self.copy_from
+        {
+            self.vec.copy_within(self.copy_from..self.tail, 0);
+            self.overwritten = self.overwritten.max(self.tail - self.copy_from);
+        }
+    }
+
+    /// Returns the multiple of the window size that is allocated.
+    pub fn multiple(&self) -> usize
+    {
+        self.multiple
+    }
+
+    /// Changes the window size to `size`.
+    /// Growing restores elements that were evicted, but are still in the vector, up to the new size.
+    /// Shrinking drops the oldest elements. The capacity is re-derived as `size * multiple`.
+    ///
+    /// Fails like `try_with_rewind`, in which case the window is left unchanged.
+    pub fn resize(&mut self, size: usize) -> Result<(), SlidingWindowError>
+    {
+        self.reserve(size, self.multiple)?;
+
+        // the incremental rewind overwrites evicted elements at the front of the vector
+        let head = self.tail.saturating_sub(size).max(self.overwritten);
+        self.size = size;
+        self.compact(head);
+        Ok(())
+    }
+
+    /// Changes the multiple of the window size that is allocated.
+    /// The window keeps its elements.
+    ///
+    /// Fails like `try_with_rewind`, in which case the window is left unchanged.
+    pub fn set_multiple(&mut self, multiple: usize) -> Result<(), SlidingWindowError>
+    {
+        self.reserve(self.size, multiple)?;
+
+        self.multiple = multiple;
+        self.compact(self.head);
+        Ok(())
+    }
+
+    /// Validates a new configuration and reserves its capacity before the window is changed.
+    fn reserve(&mut self, size: usize, multiple: usize) -> Result<(), SlidingWindowError>
+    {
+        let capacity = checked_capacity::<T>(size, multiple)?;
+        self.vec
+            .try_reserve_exact(capacity.saturating_sub(self.vec.len()))
+            .map_err(|_| SlidingWindowError::AllocationFailed { capacity })
+    }
+
+    /// Moves the elements from `head` to the tail to the front of the vector
+    /// and re-allocates the vector to `size * multiple` elements.
+    fn compact(&mut self, head: usize)
+    {
+        let len = self.tail - head;
+        self.vec.copy_within(head..self.tail, 0);
+        self.vec.truncate(len);
+        self.head = 0;
+        self.tail = len;
+        self.overwritten = 0;
+
+        // The vector is rewound once the tail hits its capacity.
+        let capacity = self.size * self.multiple;
+        if capacity > self.vec.capacity() {
+            self.vec.reserve_exact(capacity - len);
+        } else {
+            self.vec.shrink_to(capacity);
+        }
+        self.copy_from = self.rewind.copy_from(self.size, self.vec.capacity());
+    }
+}
+
+/// Returns the capacity `size * multiple` of a window that over-allocates `multiple` times its size.
+///
+/// Fails with `InvalidSize` if `size` is zero, and with `InvalidMultiple` if `multiple` is less than two
+/// or the capacity overflows, in elements or in bytes.
+pub(crate) fn checked_capacity<T>(size: usize, multiple: usize) -> Result<usize, SlidingWindowError>
+{
+    if size == 0 {
+        return Err(SlidingWindowError::InvalidSize);
+    }
+
+    match size.checked_mul(multiple) {
+        Some(capacity) if multiple >= 2 && Layout::array::<T>(capacity).is_ok() => Ok(capacity),
+        _ => Err(SlidingWindowError::InvalidMultiple { multiple }),
+    }
 }
 
 impl<T> Storage<T> for VectorStorage<T>
     where
-        T: PartialEq + Copy + Default ,
+        T: PartialEq + Copy,
 {
     fn push(&mut self, value: T) {
-        // if the array is full, rewind
+        // if the vector is full, rewind
         if self.tail > 0 && self.tail == self.vec.capacity()
         {
-            // rewind
-            for i in 0..self.size - 1
+            // rewind the newest size - 1 elements to the front,
+            // unless the incremental rewind has written them there already
+            if self.copy_from == self.vec.capacity()
             {
-                self.vec[i] = self.vec[self.head + i];
+                self.vec.copy_within(self.tail + 1 - self.size..self.tail, 0);
             }
+            self.vec.truncate(self.size - 1);
             self.head = 0;
-            self.tail = self.size;
+            self.tail = self.size - 1;
+            self.overwritten = 0;
+        }
+
+        // incremental rewind: also write the value to its position after the next rewind
+        if self.tail >= self.copy_from
+        {
+            self.vec[self.tail - self.copy_from] = value;
+            self.overwritten = self.overwritten.max(self.tail - self.copy_from + 1);
         }
 
         // push the value
         self.vec.push(value);
 
+        //increase tail cursor to next position
+        self.tail += 1;
+
         // check if the window is full,
         if self.tail - self.head > self.size
         {
             // move head cursor one position forward
             self.head += 1;
         }
+    }
 
-        //increase tail cursor to next position
-        self.tail += 1;
+    fn push_evict(&mut self, value: T) -> Option<T> {
+        let evicted = if self.filled() {
+            Some(self.vec[self.head])
+        } else {
+            None
+        };
+
+        self.push(value);
+        evicted
     }
 
-    fn first(&self) -> Result<T, String> {
-        return if self.tail != 0 {
+    fn first(&self) -> Result<T, SlidingWindowError> {
+        if self.tail != 0 {
             Ok(self.vec[self.head])
         } else {
-            Err(format!("Array is empty. Add some elements to the array first"))
-        };
+            Err(SlidingWindowError::Empty)
+        }
     }
 
-    fn last(&self) -> Result<T, String> {
-        return if self.filled() {
+    fn last(&self) -> Result<T, SlidingWindowError> {
+        if self.filled() {
             Ok(self.vec[self.tail - 1])
         } else {
-            Err(format!("Array is not yet filled. Add some elements to the array first"))
-        };
+            Err(SlidingWindowError::NotFilled { len: self.tail, size: self.size })
+        }
     }
 
     #[inline(always)]
@@ -89,12 +251,6 @@ impl<T> Storage<T> for VectorStorage<T>
     #[inline(always)]
     fn get_slice(&self) -> &[T]
     {
-        // Adjust offset in case the window is larger than the slice.
-        if self.tail > self.size
-        {
-            &self.vec[self.head + 1..self.tail]
-        } else {
-            &self.vec[self.head..self.tail]
-        }
+        &self.vec[self.head..self.tail]
     }
-}
+}
\ No newline at end of file
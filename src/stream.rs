@@ -0,0 +1,129 @@
+/*
+ * Copyright (c) 2023. Marvin Hansen <marvin.hansen@gmail.com> All rights reserved.
+ */
+
+//! `Stream` adapter that yields sliding windows over the items of another stream.
+
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use alloc::vec::Vec;
+use futures_core::Stream;
+use pin_project_lite::pin_project;
+
+use crate::sliding_window::{new_with_vector_storage, new_with_vector_storage_and_warm_up, SlidingWindow};
+use crate::storage::Storage;
+use crate::storage_vec::VectorStorage;
+use crate::warm_up::WarmUp;
+
+/// Function that copies a window into a vector.
+pub type ToVec<T> = fn(&[T]) -> Vec<T>;
+
+pin_project! {
+    /// Stream that pushes every item of the inner stream into a vector backed sliding window
+    /// and yields the window, mapped by `f`, whenever the window can be read.
+    ///
+    /// With the default strict warm-up policy, only full windows are yielded.
+    /// With `WarmUp::Partial`, a window is yielded for every item.
+    #[must_use = "streams do nothing unless polled"]
+    pub struct SlidingWindowStream<St, T, F>
+        where
+            T: PartialEq,
+            T: Copy,
+    {
+        #[pin]
+        stream: St,
+        window: SlidingWindow<VectorStorage<T>, T>,
+        f: F,
+    }
+}
+
+impl<St, T, F> SlidingWindowStream<St, T, F>
+    where
+        T: PartialEq + Copy,
+{
+    fn new(stream: St, size: usize, multiple: usize, f: F) -> Self
+    {
+        Self { stream, window: new_with_vector_storage(size, multiple), f }
+    }
+
+    /// Sets the warm-up policy, which decides if partially filled windows are yielded.
+    /// Replaces the window with a new one, so call it before polling the stream.
+    pub fn with_warm_up(mut self, warm_up: WarmUp<T>) -> Self
+    {
+        let storage = self.window.storage();
+        self.window = new_with_vector_storage_and_warm_up(storage.size(), storage.multiple(), warm_up);
+        self
+    }
+
+    /// Returns the sliding window.
+    pub fn window(&self) -> &SlidingWindow<VectorStorage<T>, T>
+    {
+        &self.window
+    }
+
+    /// Consumes the adapter and returns the inner stream.
+    pub fn into_inner(self) -> St
+    {
+        self.stream
+    }
+}
+
+impl<St, T, F, R> Stream for SlidingWindowStream<St, T, F>
+    where
+        St: Stream<Item = T>,
+        T: PartialEq + Copy,
+        F: FnMut(&[T]) -> R,
+{
+    type Item = R;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>>
+    {
+        let mut this = self.project();
+        loop {
+            match this.stream.as_mut().poll_next(cx) {
+                Poll::Ready(Some(value)) => {
+                    this.window.push(value);
+                    if let Ok(slice) = this.window.slice() {
+                        return Poll::Ready(Some((this.f)(slice)));
+                    }
+                }
+                Poll::Ready(None) => return Poll::Ready(None),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>)
+    {
+        // Without knowing the warm-up, every item may or may not yield a window.
+        (0, self.stream.size_hint().1)
+    }
+}
+
+/// Extension trait that adds sliding window adapters to every `Stream`.
+pub trait SlidingWindowStreamExt: Stream
+{
+    /// Yields a copy of the sliding window of `size` elements over the items of this stream.
+    /// `multiple` determines the capacity of the vector backed storage.
+    fn sliding_window(self, size: usize, multiple: usize) -> SlidingWindowStream<Self, Self::Item, ToVec<Self::Item>>
+        where
+            Self: Sized,
+            Self::Item: PartialEq + Copy,
+    {
+        SlidingWindowStream::new(self, size, multiple, <[Self::Item]>::to_vec)
+    }
+
+    /// Yields `f` applied to the sliding window of `size` elements over the items of this stream.
+    /// Use it to emit an aggregate of the window without copying it.
+    fn sliding_window_map<F, R>(self, size: usize, multiple: usize, f: F) -> SlidingWindowStream<Self, Self::Item, F>
+        where
+            Self: Sized,
+            Self::Item: PartialEq + Copy,
+            F: FnMut(&[Self::Item]) -> R,
+    {
+        SlidingWindowStream::new(self, size, multiple, f)
+    }
+}
+
+impl<St> SlidingWindowStreamExt for St where St: Stream {}
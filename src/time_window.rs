@@ -0,0 +1,227 @@
+/*
+ * Copyright (c) 2023. Marvin Hansen <marvin.hansen@gmail.com> All rights reserved.
+ */
+
+use alloc::vec::Vec;
+use core::cell::Cell;
+use core::time::Duration;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::error::SlidingWindowError;
+
+/// Source of timestamps for a time based sliding window.
+///
+/// Timestamps are durations since an arbitrary, but fixed, epoch of the clock.
+pub trait Clock {
+    /// Returns the current time.
+    fn now(&self) -> Duration;
+}
+
+/// Wall clock time measured since the UNIX epoch.
+#[derive(Debug, Default, Copy, Clone)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Duration {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+    }
+}
+
+/// Manually driven clock, i.e. for event timestamps or tests.
+#[derive(Debug, Default, Clone)]
+pub struct ManualClock {
+    now: Cell<Duration>,
+}
+
+impl ManualClock {
+    pub fn new(now: Duration) -> Self
+    {
+        Self { now: Cell::new(now) }
+    }
+
+    /// Sets the current time.
+    pub fn set(&self, now: Duration) {
+        self.now.set(now)
+    }
+
+    /// Moves the current time forward by `by`.
+    pub fn advance(&self, by: Duration) {
+        self.now.set(self.now.get() + by)
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> Duration {
+        self.now.get()
+    }
+}
+
+/// Sliding window over the last `duration` of time.
+///
+/// Elements are stored as `(timestamp, value)` pairs in a pre-allocated vector.
+/// Each push evicts all elements older than `duration` by advancing the head cursor.
+/// Evicted elements are only discarded once the tail hits the capacity,
+/// at which point the live elements are rewound to the front of the vector.
+/// If the live elements then fill more than half of the vector, it grows,
+/// so that rewinds stay rare when the window holds more elements than expected.
+/// Timestamps are expected to be non-decreasing.
+pub struct TimeWindow<T, C = SystemClock>
+    where
+        T: PartialEq + Copy,
+        C: Clock,
+{
+    vec: Vec<(Duration, T)>,
+    duration: Duration,
+    head: usize,
+    clock: C,
+}
+
+impl<T> TimeWindow<T, SystemClock>
+    where
+        T: PartialEq + Copy,
+{
+    /// Creates a new time window over `duration` that pre-allocates `capacity` elements.
+    pub fn new(duration: Duration, capacity: usize) -> Self
+    {
+        Self::with_clock(duration, capacity, SystemClock)
+    }
+}
+
+#[allow(clippy::len_without_is_empty)]
+impl<T, C> TimeWindow<T, C>
+    where
+        T: PartialEq + Copy,
+        C: Clock,
+{
+    /// Creates a new time window over `duration` that reads timestamps from `clock`.
+    pub fn with_clock(duration: Duration, capacity: usize, clock: C) -> Self
+    {
+        Self {
+            vec: Vec::with_capacity(capacity),
+            duration,
+            head: 0,
+            clock,
+        }
+    }
+
+    /// Pushes a new element stamped with the current time of the clock.
+    pub fn push(&mut self, value: T) {
+        let now = self.clock.now();
+        self.push_at(now, value);
+    }
+
+    /// Pushes a new element with an explicit (event) timestamp.
+    pub fn push_at(&mut self, timestamp: Duration, value: T) {
+        self.evict(timestamp);
+
+        // if the vector is full, rewind
+        if self.head > 0 && self.vec.len() == self.vec.capacity()
+        {
+            let len = self.vec.len();
+            self.vec.copy_within(self.head..len, 0);
+            self.vec.truncate(len - self.head);
+            self.head = 0;
+
+            // keep at least half of the vector spare, so that a rewind only happens
+            // after as many pushes as it copies elements
+            let len = self.vec.len();
+            if len > self.vec.capacity() / 2
+            {
+                self.vec.reserve(len);
+            }
+        }
+
+        self.vec.push((timestamp, value));
+    }
+
+    /// Evicts all elements that are older than `duration` relative to `now`.
+    /// Elements never expire if their timestamp plus `duration` overflows,
+    /// i.e. for a `duration` of `Duration::MAX`.
+    pub fn evict(&mut self, now: Duration) {
+        while self.head < self.vec.len() && self.vec[self.head].0.checked_add(self.duration).map_or(false, |end| end <= now)
+        {
+            self.head += 1;
+        }
+    }
+
+    /// Evicts all elements that are older than `duration` relative to the clock.
+    pub fn expire(&mut self) {
+        let now = self.clock.now();
+        self.evict(now);
+    }
+
+    /// Returns the first (oldest) element in the window.
+    pub fn first(&self) -> Result<T, SlidingWindowError> {
+        self.first_entry().map(|(_, v)| v)
+    }
+
+    /// Returns the last (newest) element in the window.
+    pub fn last(&self) -> Result<T, SlidingWindowError> {
+        self.last_entry().map(|(_, v)| v)
+    }
+
+    /// Returns the first (oldest) element together with its timestamp.
+    pub fn first_entry(&self) -> Result<(Duration, T), SlidingWindowError> {
+        if self.empty() {
+            Err(SlidingWindowError::Empty)
+        } else {
+            Ok(self.vec[self.head])
+        }
+    }
+
+    /// Returns the last (newest) element together with its timestamp.
+    pub fn last_entry(&self) -> Result<(Duration, T), SlidingWindowError> {
+        if self.empty() {
+            Err(SlidingWindowError::Empty)
+        } else {
+            Ok(self.vec[self.vec.len() - 1])
+        }
+    }
+
+    /// Returns the window as slice of `(timestamp, value)` pairs.
+    pub fn slice(&self) -> Result<&[(Duration, T)], SlidingWindowError> {
+        if self.empty() {
+            Err(SlidingWindowError::Empty)
+        } else {
+            Ok(self.get_slice())
+        }
+    }
+
+    /// Returns the window as slice of `(timestamp, value)` pairs, which may be empty.
+    #[inline(always)]
+    pub fn get_slice(&self) -> &[(Duration, T)] {
+        &self.vec[self.head..]
+    }
+
+    /// Returns the values of the window as vector.
+    pub fn vec(&self) -> Result<Vec<T>, SlidingWindowError> {
+        self.slice().map(|s| s.iter().map(|(_, v)| *v).collect())
+    }
+
+    /// Returns the number of elements in the window.
+    pub fn len(&self) -> usize {
+        self.vec.len() - self.head
+    }
+
+    /// Returns true if the window is empty.
+    pub fn empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the time span covered by the window.
+    pub fn duration(&self) -> Duration {
+        self.duration
+    }
+
+    /// Returns the number of elements the vector can hold before the next rewind.
+    pub fn capacity(&self) -> usize {
+        self.vec.capacity()
+    }
+
+    /// Returns the clock of the window.
+    pub fn clock(&self) -> &C {
+        &self.clock
+    }
+}
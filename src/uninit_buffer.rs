@@ -0,0 +1,115 @@
+/*
+ * Copyright (c) 2023. Marvin Hansen <marvin.hansen@gmail.com> All rights reserved.
+ */
+
+use core::marker::PhantomData;
+use core::mem::MaybeUninit;
+use core::{ptr, slice};
+
+/// Over-allocated buffer of which only the slots of the current window are initialized.
+///
+/// Holds the unsafe logic that the uninitialized array and the owned storage share.
+/// `B` is the backing buffer, i.e. an array or a boxed slice of `MaybeUninit<T>`.
+/// Elements leaving the window are moved out on push, the rewind moves the remaining
+/// elements to the front without cloning them, and drop drops the window exactly once.
+pub(crate) struct UninitBuffer<B, T>
+    where
+        B: AsRef<[MaybeUninit<T>]> + AsMut<[MaybeUninit<T>]>,
+{
+    buf: B,
+    size: usize,
+    head: usize,
+    tail: usize,
+    // the buffer owns elements of type T
+    _marker: PhantomData<T>,
+}
+
+impl<B, T> UninitBuffer<B, T>
+    where
+        B: AsRef<[MaybeUninit<T>]> + AsMut<[MaybeUninit<T>]>,
+{
+    /// Creates an empty window of `size` elements over `buf`, whose slots are all uninitialized.
+    pub(crate) const fn new(buf: B, size: usize) -> Self
+    {
+        Self {
+            buf,
+            size,
+            head: 0,
+            tail: 0,
+            _marker: PhantomData,
+        }
+    }
+
+    pub(crate) fn push_evict(&mut self, value: T) -> Option<T>
+    {
+        let buf = self.buf.as_mut();
+
+        // if the window is full, move the first element out
+        let evicted = if self.tail - self.head == self.size && self.size > 0
+        {
+            // Safety: slots within head..tail are initialized and the head slot
+            // is not read again, because the head cursor moves past it.
+            let evicted = unsafe { buf[self.head].assume_init_read() };
+            self.head += 1;
+            Some(evicted)
+        } else {
+            None
+        };
+
+        // if the buffer is full, rewind
+        if self.tail > 0 && self.tail == buf.len()
+        {
+            let len = self.tail - self.head;
+            // Safety: moves the initialized slots head..tail to the front.
+            // The source slots are considered uninitialized afterwards.
+            unsafe {
+                let base = buf.as_mut_ptr();
+                ptr::copy(base.add(self.head), base, len);
+            }
+            self.head = 0;
+            self.tail = len;
+        }
+
+        // push the value
+        buf[self.tail].write(value);
+
+        //increase tail cursor to next position
+        self.tail += 1;
+
+        evicted
+    }
+
+    #[inline(always)]
+    pub(crate) fn tail(&self) -> usize
+    {
+        self.tail
+    }
+
+    #[inline(always)]
+    pub(crate) fn size(&self) -> usize
+    {
+        self.size
+    }
+
+    #[inline(always)]
+    pub(crate) fn get_slice(&self) -> &[T]
+    {
+        // Safety: slots within head..tail are initialized.
+        unsafe {
+            slice::from_raw_parts(self.buf.as_ref().as_ptr().add(self.head) as *const T, self.tail - self.head)
+        }
+    }
+}
+
+impl<B, T> Drop for UninitBuffer<B, T>
+    where
+        B: AsRef<[MaybeUninit<T>]> + AsMut<[MaybeUninit<T>]>,
+{
+    fn drop(&mut self) {
+        // Safety: slots within head..tail are initialized and dropped exactly once.
+        unsafe {
+            let window = self.buf.as_mut().as_mut_ptr().add(self.head) as *mut T;
+            ptr::drop_in_place(ptr::slice_from_raw_parts_mut(window, self.tail - self.head));
+        }
+    }
+}
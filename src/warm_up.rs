@@ -0,0 +1,19 @@
+/*
+ * Copyright (c) 2023. Marvin Hansen <marvin.hansen@gmail.com> All rights reserved.
+ */
+
+/// Defines how a sliding window behaves before it is filled.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum WarmUp<T> {
+    /// `last`, `slice`, `vec` and `arr` return an error until the window is filled.
+    #[default]
+    Strict,
+    /// `last`, `slice` and `vec` return whatever elements the window holds.
+    /// `arr` succeeds when its length matches the number of elements held.
+    Partial,
+    /// The window is filled with the given value on construction
+    /// and therefore behaves as filled from the start.
+    /// Use `Padded(T::default())` to pad with the default value.
+    Padded(T),
+}
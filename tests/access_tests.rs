@@ -0,0 +1,65 @@
+use sliding_window::error::SlidingWindowError;
+use sliding_window::sliding_window::{new_with_array_storage, new_with_vector_storage, SlidingWindow};
+use sliding_window::storage::Storage;
+
+const SIZE: usize = 4;
+const MULT: usize = 2;
+const CAPACITY: usize = 8;
+
+fn check<S: Storage<i32>>(window: &mut SlidingWindow<S, i32>) {
+    assert_eq!(window.get(0), Err(SlidingWindowError::OutOfBounds { index: 0, len: 0 }));
+    assert_eq!(window.lag(0), Err(SlidingWindowError::OutOfBounds { index: 0, len: 0 }));
+
+    for i in 0..40 {
+        // Squares make the differences distinct for each lag.
+        window.push(i * i);
+
+        let first = (i - SIZE as i32 + 1).max(0);
+        let len = (i - first + 1) as usize;
+        for k in 0..len {
+            let oldest = first + k as i32;
+            let newest = i - k as i32;
+            assert_eq!(window.get(k), Ok(oldest * oldest));
+            assert_eq!(window[k], oldest * oldest);
+            assert_eq!(window.get_back(k), Ok(newest * newest));
+            assert_eq!(window.lag(k), Ok(newest * newest));
+            assert_eq!(window.diff(k), Ok(i * i - newest * newest));
+            unsafe {
+                assert_eq!(window.get_unchecked(k), oldest * oldest);
+                assert_eq!(window.get_back_unchecked(k), newest * newest);
+            }
+        }
+        assert_eq!(window.get(len), Err(SlidingWindowError::OutOfBounds { index: len, len }));
+        assert_eq!(window.get_back(len), Err(SlidingWindowError::OutOfBounds { index: len, len }));
+        assert_eq!(window.diff(len), Err(SlidingWindowError::OutOfBounds { index: len, len }));
+    }
+}
+
+#[test]
+fn test_vector_backed() {
+    let mut window = new_with_vector_storage(SIZE, MULT);
+    check(&mut window);
+}
+
+#[test]
+fn test_array_backed() {
+    let mut window = new_with_array_storage::<i32, SIZE, CAPACITY>();
+    check(&mut window);
+}
+
+#[test]
+fn test_diff_float() {
+    let mut window = new_with_vector_storage(SIZE, MULT);
+    window.push(1.5);
+    window.push(4.0);
+    assert_eq!(window.diff(0), Ok(0.0));
+    assert_eq!(window.diff(1), Ok(2.5));
+}
+
+#[test]
+#[should_panic]
+fn test_index_out_of_bounds() {
+    let mut window = new_with_vector_storage(SIZE, MULT);
+    window.push(1);
+    let _ = window[1];
+}
@@ -2,7 +2,9 @@
  * Copyright (c) 2023. Marvin Hansen <marvin.hansen@gmail.com> All rights reserved.
  */
 
+#![allow(clippy::bool_assert_comparison, clippy::get_first)]
 
+use sliding_window::error::SlidingWindowError;
 use sliding_window::sliding_window::{new_with_array_storage, SlidingWindow};
 use sliding_window::storage_array::ArrayStorage;
 
@@ -263,4 +265,47 @@ fn test_arr() {
     assert_eq!(arr[1].dats, 0);
     assert_eq!(arr[2].dats, 42);
     assert_eq!(arr[3].dats, 0);
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_errors() {
+    let mut window = get_sliding_window();
+    assert_eq!(window.first(), Err(SlidingWindowError::Empty));
+    assert_eq!(window.last(), Err(SlidingWindowError::NotFilled { len: 0, size: SIZE }));
+
+    let d = Data { dats: 0 };
+    window.push(d);
+    assert_eq!(window.slice().unwrap_err(), SlidingWindowError::NotFilled { len: 1, size: SIZE });
+    assert_eq!(window.arr::<SIZE>().unwrap_err(), SlidingWindowError::NotFilled { len: 1, size: SIZE });
+
+    window.push(d);
+    window.push(d);
+    window.push(d);
+    assert_eq!(window.filled(), true);
+
+    let res = window.arr::<2>();
+    assert_eq!(res.unwrap_err(), SlidingWindowError::SizeMismatch { expected: SIZE, got: 2 });
+
+    let err = SlidingWindowError::NotFilled { len: 1, size: SIZE };
+    assert_eq!(err.to_string(), "Sliding window is not yet filled: 1 of 4 elements");
+}
+
+#[test]
+fn test_rewind() {
+    let mut window: SlidingWindow<ArrayStorage<Data, SIZE, 8>, Data> = new_with_array_storage();
+
+    // Push well past the capacity of 8 to run through several rewinds.
+    for i in 0..40 {
+        window.push(Data { dats: i });
+
+        let first = (i - SIZE as i32 + 1).max(0);
+        assert_eq!(window.first().unwrap().dats, first);
+
+        if window.filled() {
+            let slice = window.slice().expect("Failed to get slice");
+            let dats: Vec<i32> = slice.iter().map(|d| d.dats).collect();
+            assert_eq!(dats, (first..=i).collect::<Vec<i32>>());
+            assert_eq!(window.last().unwrap().dats, i);
+        }
+    }
+}
@@ -0,0 +1,129 @@
+#![allow(clippy::bool_assert_comparison)]
+
+use sliding_window::builder::{DEFAULT_MULTIPLE, SlidingWindowBuilder};
+use sliding_window::error::SlidingWindowError;
+use sliding_window::rewind::Rewind;
+use sliding_window::warm_up::WarmUp;
+
+#[test]
+fn test_build_vector() {
+    let mut window = SlidingWindowBuilder::new().size(3).multiple(4).build().unwrap();
+    assert_eq!(window.size(), 3);
+    assert_eq!(window.multiple(), 4);
+
+    for i in 0..20 {
+        window.push(i);
+    }
+    assert_eq!(window.slice(), Ok(&[17, 18, 19][..]));
+}
+
+#[test]
+fn test_build_vector_default_multiple() {
+    let window = SlidingWindowBuilder::<f64>::new().size(3).build().unwrap();
+    assert_eq!(window.multiple(), DEFAULT_MULTIPLE);
+    assert_eq!(window.warm_up(), &WarmUp::Strict);
+}
+
+#[test]
+fn test_build_vector_warm_up() {
+    let window = SlidingWindowBuilder::new().size(3).warm_up(WarmUp::Padded(7)).build().unwrap();
+    assert_eq!(window.filled(), true);
+    assert_eq!(window.slice(), Ok(&[7, 7, 7][..]));
+}
+
+#[test]
+fn test_build_vector_invalid_size() {
+    let res = SlidingWindowBuilder::<i32>::new().build();
+    assert_eq!(res.err(), Some(SlidingWindowError::InvalidSize));
+
+    let res = SlidingWindowBuilder::<i32>::new().size(0).build();
+    assert_eq!(res.err(), Some(SlidingWindowError::InvalidSize));
+}
+
+#[test]
+fn test_build_vector_invalid_multiple() {
+    for multiple in [0, 1] {
+        let res = SlidingWindowBuilder::<i32>::new().size(4).multiple(multiple).build();
+        assert_eq!(res.err(), Some(SlidingWindowError::InvalidMultiple { multiple }));
+    }
+
+    let res = SlidingWindowBuilder::<i32>::new().size(4).multiple(usize::MAX).build();
+    assert_eq!(res.err(), Some(SlidingWindowError::InvalidMultiple { multiple: usize::MAX }));
+
+    // the capacity fits into usize, but its size in bytes does not fit into isize
+    let res = SlidingWindowBuilder::<u64>::new().size(usize::MAX / 16).multiple(2).build();
+    assert_eq!(res.err(), Some(SlidingWindowError::InvalidMultiple { multiple: 2 }));
+}
+
+#[test]
+fn test_build_vector_allocation_failed() {
+    // a valid layout, but larger than the address space
+    let size = isize::MAX as usize / 2;
+    let res = SlidingWindowBuilder::<u8>::new().size(size).multiple(2).build();
+    assert_eq!(res.err(), Some(SlidingWindowError::AllocationFailed { capacity: size * 2 }));
+}
+
+#[test]
+fn test_build_array() {
+    let mut window = SlidingWindowBuilder::new().build_array::<3, 6>().unwrap();
+    for i in 0..20 {
+        window.push(i);
+    }
+    assert_eq!(window.slice(), Ok(&[17, 18, 19][..]));
+
+    let window = SlidingWindowBuilder::new().size(3).warm_up(WarmUp::Padded(1)).build_array::<3, 6>().unwrap();
+    assert_eq!(window.slice(), Ok(&[1, 1, 1][..]));
+}
+
+#[test]
+fn test_build_array_size_mismatch() {
+    let res = SlidingWindowBuilder::<i32>::new().size(4).build_array::<3, 6>();
+    assert_eq!(res.err(), Some(SlidingWindowError::SizeMismatch { expected: 3, got: 4 }));
+}
+
+#[test]
+fn test_build_selected_array() {
+    // the configured size fits the const generic capacity
+    for builder in [SlidingWindowBuilder::new(), SlidingWindowBuilder::new().size(3)] {
+        let mut window = builder.rewind(Rewind::Incremental).build_selected::<3, 6>().unwrap();
+        assert_eq!(window.is_array(), true);
+        assert_eq!(window.rewind(), Rewind::Incremental);
+
+        for i in 0..20 {
+            window.push(i);
+        }
+        assert_eq!(window.slice(), Ok(&[17, 18, 19][..]));
+    }
+}
+
+#[test]
+fn test_build_selected_vector() {
+    let mut window = SlidingWindowBuilder::new().size(5).multiple(4).warm_up(WarmUp::Padded(0)).build_selected::<3, 6>().unwrap();
+    assert_eq!(window.is_array(), false);
+    assert_eq!(window.size(), 5);
+    assert_eq!(window.slice(), Ok(&[0, 0, 0, 0, 0][..]));
+
+    for i in 0..20 {
+        window.push(i);
+    }
+    assert_eq!(window.slice(), Ok(&[15, 16, 17, 18, 19][..]));
+
+    // the vector backend is validated like `build`
+    let res = SlidingWindowBuilder::<i32>::new().size(0).build_selected::<3, 6>();
+    assert_eq!(res.err(), Some(SlidingWindowError::InvalidSize));
+    let res = SlidingWindowBuilder::<i32>::new().size(5).multiple(1).build_selected::<3, 6>();
+    assert_eq!(res.err(), Some(SlidingWindowError::InvalidMultiple { multiple: 1 }));
+}
+
+#[test]
+fn test_error_display() {
+    assert_eq!(SlidingWindowError::InvalidSize.to_string(), "Invalid size. The window size must be greater than zero");
+    assert_eq!(
+        SlidingWindowError::InvalidMultiple { multiple: 1 }.to_string(),
+        "Invalid multiple: 1. The multiple must be at least 2, and size times multiple must not overflow"
+    );
+    assert_eq!(
+        SlidingWindowError::AllocationFailed { capacity: 8 }.to_string(),
+        "Allocation failed: the buffer of 8 elements could not be allocated"
+    );
+}
@@ -0,0 +1,108 @@
+#![allow(clippy::bool_assert_comparison)]
+#![cfg(not(loom))]
+
+use std::thread;
+
+use sliding_window::concurrent_window::new_concurrent_window;
+use sliding_window::error::SlidingWindowError;
+
+const SIZE: usize = 4;
+const CAPACITY: usize = 8;
+
+#[test]
+fn test_empty() {
+    let (writer, reader) = new_concurrent_window::<u64, SIZE, CAPACITY>();
+    assert_eq!(writer.empty(), true);
+    assert_eq!(reader.empty(), true);
+    assert_eq!(reader.size(), SIZE);
+    assert_eq!(reader.first(), Err(SlidingWindowError::Empty));
+    assert_eq!(reader.last(), Err(SlidingWindowError::NotFilled { len: 0, size: SIZE }));
+    assert_eq!(reader.arr(), Err(SlidingWindowError::NotFilled { len: 0, size: SIZE }));
+}
+
+#[test]
+fn test_push() {
+    let (mut writer, reader) = new_concurrent_window::<u64, SIZE, CAPACITY>();
+
+    writer.push(1);
+    writer.push(2);
+    assert_eq!(writer.len(), 2);
+    assert_eq!(reader.len(), 2);
+    assert_eq!(reader.filled(), false);
+    assert_eq!(reader.first(), Ok(1));
+    assert_eq!(reader.vec(), Err(SlidingWindowError::NotFilled { len: 2, size: SIZE }));
+
+    writer.push(3);
+    writer.push(4);
+    assert_eq!(writer.filled(), true);
+    assert_eq!(reader.filled(), true);
+    assert_eq!(reader.last(), Ok(4));
+    assert_eq!(reader.arr(), Ok([1, 2, 3, 4]));
+    assert_eq!(reader.vec(), Ok(vec![1, 2, 3, 4]));
+}
+
+#[test]
+fn test_rewind() {
+    let (mut writer, reader) = new_concurrent_window::<f64, SIZE, CAPACITY>();
+
+    for i in 0..3 * CAPACITY {
+        writer.push(i as f64);
+
+        let first = i.saturating_sub(SIZE - 1);
+        assert_eq!(reader.first(), Ok(first as f64));
+        assert_eq!(reader.len(), i + 1 - first);
+    }
+
+    let last = (3 * CAPACITY - 1) as f64;
+    assert_eq!(reader.arr(), Ok([last - 3.0, last - 2.0, last - 1.0, last]));
+}
+
+#[test]
+fn test_reader_clone() {
+    let (mut writer, reader) = new_concurrent_window::<i32, SIZE, CAPACITY>();
+    let other = reader.clone();
+    let third = writer.reader();
+
+    for i in 0..SIZE as i32 {
+        writer.push(-i);
+    }
+
+    assert_eq!(reader.arr(), Ok([0, -1, -2, -3]));
+    assert_eq!(other.arr(), Ok([0, -1, -2, -3]));
+    assert_eq!(third.arr(), Ok([0, -1, -2, -3]));
+}
+
+#[test]
+fn test_concurrent_reads() {
+    const PUSHES: u64 = 100_000;
+
+    let (mut writer, reader) = new_concurrent_window::<u64, SIZE, CAPACITY>();
+
+    let readers: Vec<_> = (0..4)
+        .map(|_| {
+            let reader = reader.clone();
+            thread::spawn(move || {
+                let mut newest = 0;
+                while newest < PUSHES - 1 {
+                    if let Ok(arr) = reader.arr() {
+                        // Every copy is a run of consecutive pushes.
+                        for i in 1..SIZE {
+                            assert_eq!(arr[i], arr[i - 1] + 1);
+                        }
+                        // Readers never go back in time.
+                        assert!(arr[SIZE - 1] >= newest);
+                        newest = arr[SIZE - 1];
+                    }
+                }
+            })
+        })
+        .collect();
+
+    for i in 0..PUSHES {
+        writer.push(i);
+    }
+
+    for reader in readers {
+        reader.join().unwrap();
+    }
+}
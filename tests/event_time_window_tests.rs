@@ -0,0 +1,177 @@
+#![allow(clippy::bool_assert_comparison)]
+
+use std::time::Duration;
+
+use sliding_window::error::SlidingWindowError;
+use sliding_window::event_time_window::{Arrival, EventTimeWindow, LatePolicy};
+
+const CAPACITY: usize = 8;
+
+fn secs(s: u64) -> Duration {
+    Duration::from_secs(s)
+}
+
+fn get_window(policy: LatePolicy) -> EventTimeWindow<i32> {
+    // 10 seconds of event time, elements may arrive up to 2 seconds out of order
+    EventTimeWindow::new(secs(10), secs(2), policy, CAPACITY)
+}
+
+fn timestamps(window: &EventTimeWindow<i32>) -> Vec<u64> {
+    window.get_slice().iter().map(|(t, _)| t.as_secs()).collect()
+}
+
+#[test]
+fn test_empty() {
+    let window = get_window(LatePolicy::default());
+    assert_eq!(window.empty(), true);
+    assert_eq!(window.policy(), LatePolicy::Drop);
+    assert_eq!(window.duration(), secs(10));
+    assert_eq!(window.allowed_lateness(), secs(2));
+    assert_eq!(window.watermark(), secs(0));
+    assert_eq!(window.first_entry(), Err(SlidingWindowError::Empty));
+    assert_eq!(window.last_entry(), Err(SlidingWindowError::Empty));
+    assert_eq!(window.vec(), Err(SlidingWindowError::Empty));
+}
+
+#[test]
+fn test_out_of_order_within_lateness() {
+    let mut window = get_window(LatePolicy::Drop);
+
+    assert_eq!(window.push_at(secs(10), 10), Arrival::OnTime);
+    assert_eq!(window.push_at(secs(12), 12), Arrival::OnTime);
+    assert_eq!(window.watermark(), secs(10));
+
+    // older than the newest element, but not older than the watermark
+    assert_eq!(window.push_at(secs(11), 11), Arrival::OnTime);
+    assert_eq!(window.push_at(secs(10), 100), Arrival::OnTime);
+
+    assert_eq!(timestamps(&window), vec![10, 10, 11, 12]);
+    assert_eq!(window.vec(), Ok(vec![10, 100, 11, 12]));
+    assert_eq!(window.first_entry(), Ok((secs(10), 10)));
+    assert_eq!(window.last_entry(), Ok((secs(12), 12)));
+}
+
+#[test]
+fn test_complete_and_pending() {
+    let mut window = get_window(LatePolicy::Drop);
+    for t in [1, 2, 3, 5, 4] {
+        window.push_at(secs(t), t as i32);
+    }
+
+    assert_eq!(window.watermark(), secs(3));
+    assert_eq!(window.complete(), &[(secs(1), 1), (secs(2), 2)]);
+    assert_eq!(window.pending(), &[(secs(3), 3), (secs(4), 4), (secs(5), 5)]);
+}
+
+#[test]
+fn test_late_drop() {
+    let mut window = get_window(LatePolicy::Drop);
+    window.push_at(secs(10), 1);
+
+    assert_eq!(window.push_at(secs(7), 2), Arrival::Dropped);
+    assert_eq!(window.len(), 1);
+    assert_eq!(window.late(), &[]);
+}
+
+#[test]
+fn test_late_side_output() {
+    let mut window = get_window(LatePolicy::SideOutput);
+    window.push_at(secs(10), 1);
+
+    assert_eq!(window.push_at(secs(7), 2), Arrival::SideOutput);
+    assert_eq!(window.push_at(secs(5), 3), Arrival::SideOutput);
+    assert_eq!(window.len(), 1);
+    assert_eq!(window.late(), &[(secs(7), 2), (secs(5), 3)]);
+
+    let late: Vec<_> = window.drain_late().collect();
+    assert_eq!(late.len(), 2);
+    assert_eq!(window.late(), &[]);
+}
+
+#[test]
+fn test_late_update() {
+    let mut window = get_window(LatePolicy::Update);
+    window.push_at(secs(10), 10);
+    window.push_at(secs(20), 20);
+    assert_eq!(window.watermark(), secs(18));
+
+    // late, but within the duration of the window
+    assert_eq!(window.push_at(secs(15), 15), Arrival::Updated);
+    // late and outside the duration of the window
+    assert_eq!(window.push_at(secs(8), 8), Arrival::Dropped);
+
+    assert_eq!(timestamps(&window), vec![10, 15, 20]);
+    assert_eq!(window.complete().len(), 2);
+}
+
+#[test]
+fn test_eviction() {
+    let mut window = get_window(LatePolicy::Drop);
+    for t in 0..30 {
+        window.push_at(secs(t), t as i32);
+    }
+
+    // the watermark is at 27, so elements older than 17 are evicted
+    assert_eq!(window.watermark(), secs(27));
+    assert_eq!(window.first_entry(), Ok((secs(18), 18)));
+    assert_eq!(window.last_entry(), Ok((secs(29), 29)));
+    assert_eq!(window.len(), 12);
+}
+
+#[test]
+fn test_advance_watermark() {
+    let mut window = get_window(LatePolicy::Drop);
+    window.push_at(secs(5), 5);
+    window.push_at(secs(6), 6);
+
+    window.advance_watermark(secs(15));
+    assert_eq!(window.watermark(), secs(15));
+    assert_eq!(window.vec(), Ok(vec![6]));
+
+    // the watermark never moves backwards
+    window.advance_watermark(secs(1));
+    assert_eq!(window.watermark(), secs(15));
+    assert_eq!(window.push_at(secs(14), 14), Arrival::Dropped);
+}
+
+#[test]
+fn test_rewind_matches_sorted_order() {
+    let mut window = get_window(LatePolicy::Drop);
+
+    // every fourth pair arrives swapped
+    let mut expected = Vec::new();
+    for t in 0..200u64 {
+        let t = if t % 4 == 2 { t + 1 } else if t % 4 == 3 { t - 1 } else { t };
+        assert_eq!(window.push_at(secs(t), t as i32), Arrival::OnTime);
+        expected.push(t);
+    }
+
+    expected.sort();
+    let watermark = window.watermark().as_secs();
+    expected.retain(|&t| t + 10 > watermark);
+    assert_eq!(timestamps(&window), expected);
+}
+
+#[test]
+fn test_unbounded_duration() {
+    // A window over the maximum duration never evicts, and late elements are never too old.
+    let mut window = EventTimeWindow::new(Duration::MAX, secs(2), LatePolicy::Update, CAPACITY);
+    assert_eq!(window.push_at(secs(5), 5), Arrival::OnTime);
+    assert_eq!(window.push_at(secs(20), 20), Arrival::OnTime);
+    assert_eq!(window.push_at(secs(1), 1), Arrival::Updated);
+
+    window.advance_watermark(Duration::MAX);
+    assert_eq!(timestamps(&window), vec![1, 5, 20]);
+}
+
+#[test]
+fn test_rewind_headroom() {
+    // the window holds 7 of 8 elements, so without headroom every push would rewind
+    let mut window = EventTimeWindow::new(secs(7), Duration::ZERO, LatePolicy::Drop, CAPACITY);
+
+    for i in 0..100 {
+        assert_eq!(window.push_at(secs(i), i as i32), Arrival::OnTime);
+        assert_eq!(window.len(), (i as usize + 1).min(7));
+    }
+    assert_eq!(window.capacity() >= 2 * 7, true);
+}
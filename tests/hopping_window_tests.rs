@@ -0,0 +1,115 @@
+#![allow(clippy::bool_assert_comparison)]
+
+use sliding_window::hopping_window::HoppingWindow;
+use sliding_window::sliding_window::{new_with_array_storage, new_with_array_storage_and_warm_up, new_with_ring_storage, new_with_vector_storage};
+use sliding_window::warm_up::WarmUp;
+
+const SIZE: usize = 4;
+const CAPACITY: usize = 8;
+
+#[test]
+fn test_hopping() {
+    let mut hopping = HoppingWindow::new(new_with_array_storage::<i32, SIZE, CAPACITY>(), 2);
+    assert_eq!(hopping.step(), 2);
+
+    let mut windows = Vec::new();
+    for i in 1..=10 {
+        if let Some(window) = hopping.push(i) {
+            windows.push(window.to_vec());
+        }
+    }
+
+    assert_eq!(windows, vec![vec![1, 2, 3, 4], vec![3, 4, 5, 6], vec![5, 6, 7, 8], vec![7, 8, 9, 10]]);
+}
+
+#[test]
+fn test_tumbling() {
+    let mut tumbling = HoppingWindow::tumbling(new_with_vector_storage(3, 2));
+    assert_eq!(tumbling.step(), 3);
+
+    let mut windows = Vec::new();
+    for i in 1..=10 {
+        if let Some(window) = tumbling.push(i) {
+            windows.push(window.to_vec());
+        }
+    }
+
+    assert_eq!(windows, vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]]);
+    assert_eq!(tumbling.window().slice(), Ok(&[8, 9, 10][..]));
+}
+
+#[test]
+fn test_step_larger_than_size() {
+    let mut hopping = HoppingWindow::new(new_with_vector_storage(2, 4), 5);
+
+    let mut windows = Vec::new();
+    for i in 1..=12 {
+        if let Some(window) = hopping.push(i) {
+            windows.push(window.to_vec());
+        }
+    }
+
+    assert_eq!(windows, vec![vec![1, 2], vec![6, 7], vec![11, 12]]);
+}
+
+#[test]
+fn test_ring_backed() {
+    // the ring wraps around for most windows, which are still reported every step pushes
+    let mut hopping = HoppingWindow::new(new_with_ring_storage(5), 2);
+
+    let mut windows = Vec::new();
+    for i in 1..=12 {
+        if let Some(window) = hopping.push(i) {
+            windows.push(window.to_vec());
+        }
+    }
+
+    assert_eq!(windows, vec![vec![1, 2, 3, 4, 5], vec![3, 4, 5, 6, 7], vec![5, 6, 7, 8, 9], vec![7, 8, 9, 10, 11]]);
+    assert_eq!(hopping.window().iter().copied().collect::<Vec<i32>>(), vec![8, 9, 10, 11, 12]);
+}
+
+#[test]
+fn test_push_with() {
+    let mut hopping = HoppingWindow::new(new_with_array_storage::<i32, SIZE, CAPACITY>(), 3);
+
+    let mut sums = Vec::new();
+    let mut ready = 0;
+    for i in 1..=20 {
+        if hopping.push_with(i, |window| sums.push(window.iter().sum::<i32>())) {
+            ready += 1;
+        }
+    }
+
+    // windows end at 4, 7, 10, 13, 16 and 19
+    assert_eq!(ready, 6);
+    assert_eq!(sums, vec![10, 22, 34, 46, 58, 70]);
+}
+
+#[test]
+fn test_partial_warm_up() {
+    let mut hopping = HoppingWindow::new(new_with_array_storage_and_warm_up::<i32, SIZE, CAPACITY>(WarmUp::Partial), 2);
+
+    assert_eq!(hopping.push(1), Some(&[1][..]));
+    assert_eq!(hopping.push(2), None);
+    assert_eq!(hopping.push(3), Some(&[1, 2, 3][..]));
+    assert_eq!(hopping.push(4), None);
+    assert_eq!(hopping.push(5), Some(&[2, 3, 4, 5][..]));
+}
+
+#[test]
+fn test_into_inner() {
+    let mut hopping = HoppingWindow::tumbling(new_with_array_storage::<i32, SIZE, CAPACITY>());
+    for i in 0..6 {
+        hopping.push(i);
+    }
+
+    let window = hopping.into_inner();
+    assert_eq!(window.filled(), true);
+    assert_eq!(window.last(), Ok(5));
+}
+
+#[test]
+#[should_panic]
+fn test_zero_step() {
+    HoppingWindow::new(new_with_array_storage::<i32, SIZE, CAPACITY>(), 0);
+}
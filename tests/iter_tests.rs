@@ -0,0 +1,105 @@
+use sliding_window::error::SlidingWindowError;
+use sliding_window::sliding_window::{new_with_array_storage, new_with_vector_storage, SlidingWindow};
+use sliding_window::storage::Storage;
+
+const SIZE: usize = 4;
+const MULT: usize = 2;
+const CAPACITY: usize = 8;
+
+fn check<S: Storage<i32>>(window: &mut SlidingWindow<S, i32>) {
+    assert_eq!(window.iter().len(), 0);
+    assert_eq!(window.iter().next(), None);
+
+    for i in 0..40 {
+        window.push(i);
+
+        let first = (i - SIZE as i32 + 1).max(0);
+        let expected: Vec<i32> = (first..=i).collect();
+
+        let iter = window.iter();
+        assert_eq!(iter.len(), expected.len());
+        assert_eq!(iter.copied().collect::<Vec<i32>>(), expected);
+
+        let newest_first: Vec<i32> = window.iter_newest_first().copied().collect();
+        assert_eq!(newest_first, expected.iter().rev().copied().collect::<Vec<i32>>());
+
+        let mut borrowed = Vec::new();
+        for &value in &*window {
+            borrowed.push(value);
+        }
+        assert_eq!(borrowed, expected);
+    }
+}
+
+#[test]
+fn test_vector_backed() {
+    let mut window = new_with_vector_storage(SIZE, MULT);
+    check(&mut window);
+}
+
+#[test]
+fn test_array_backed() {
+    let mut window = new_with_array_storage::<i32, SIZE, CAPACITY>();
+    check(&mut window);
+}
+
+#[test]
+fn test_double_ended() {
+    let mut window = new_with_vector_storage(SIZE, MULT);
+    window.push(1);
+    window.push(2);
+    window.push(3);
+
+    let mut iter = window.iter();
+    assert_eq!(iter.next(), Some(&1));
+    assert_eq!(iter.next_back(), Some(&3));
+    assert_eq!(iter.len(), 1);
+    assert_eq!(iter.next(), Some(&2));
+    assert_eq!(iter.next_back(), None);
+}
+
+/// Minimal custom backend that only implements the required methods.
+struct LastOne {
+    value: [i32; 1],
+    tail: usize,
+}
+
+impl Storage<i32> for LastOne {
+    fn push_evict(&mut self, value: i32) -> Option<i32> {
+        let evicted = self.last().ok();
+        self.value[0] = value;
+        self.tail += 1;
+        evicted
+    }
+
+    fn first(&self) -> Result<i32, SlidingWindowError> {
+        self.last()
+    }
+
+    fn last(&self) -> Result<i32, SlidingWindowError> {
+        if self.tail == 0 { Err(SlidingWindowError::Empty) } else { Ok(self.value[0]) }
+    }
+
+    fn tail(&self) -> usize {
+        self.tail
+    }
+
+    fn size(&self) -> usize {
+        1
+    }
+
+    fn get_slice(&self) -> &[i32] {
+        &self.value[..self.tail.min(1)]
+    }
+}
+
+#[test]
+fn test_custom_storage() {
+    let mut storage = LastOne { value: [0], tail: 0 };
+    assert_eq!(storage.iter().count(), 0);
+
+    storage.push(7);
+    storage.push(8);
+    assert_eq!(storage.iter().copied().collect::<Vec<i32>>(), vec![8]);
+    assert_eq!(storage.iter_newest_first().copied().collect::<Vec<i32>>(), vec![8]);
+}
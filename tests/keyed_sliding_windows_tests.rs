@@ -0,0 +1,151 @@
+#![allow(clippy::bool_assert_comparison)]
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use sliding_window::error::SlidingWindowError;
+use sliding_window::keyed_sliding_windows::KeyedSlidingWindows;
+use sliding_window::time_window::ManualClock;
+
+const SIZE: usize = 3;
+const MULTIPLE: usize = 2;
+
+fn secs(s: u64) -> Duration {
+    Duration::from_secs(s)
+}
+
+fn get_windows() -> KeyedSlidingWindows<&'static str, i32, ManualClock> {
+    KeyedSlidingWindows::with_clock(SIZE, MULTIPLE, secs(60), ManualClock::new(secs(0))).unwrap()
+}
+
+#[test]
+fn test_empty() {
+    let windows = get_windows();
+    assert_eq!(windows.empty(), true);
+    assert_eq!(windows.len(), 0);
+    assert_eq!(windows.size(), SIZE);
+    assert_eq!(windows.ttl(), secs(60));
+    assert_eq!(windows.get(&"AAPL"), None);
+    assert_eq!(windows.slice(&"AAPL"), Err(SlidingWindowError::Empty));
+    assert_eq!(windows.contains_key(&"AAPL"), false);
+}
+
+#[test]
+fn test_lazy_creation() {
+    let mut windows = get_windows();
+
+    windows.push("AAPL", 1);
+    windows.push("MSFT", 10);
+    windows.push("AAPL", 2);
+
+    assert_eq!(windows.len(), 2);
+    assert_eq!(windows.get(&"AAPL"), Some(&[1, 2][..]));
+    assert_eq!(windows.get(&"MSFT"), Some(&[10][..]));
+    assert_eq!(windows.slice(&"AAPL"), Err(SlidingWindowError::NotFilled { len: 2, size: SIZE }));
+
+    windows.push("AAPL", 3);
+    assert_eq!(windows.slice(&"AAPL"), Ok(&[1, 2, 3][..]));
+}
+
+#[test]
+fn test_rewind_per_key() {
+    let mut windows = get_windows();
+
+    for i in 0..20 {
+        windows.push("AAPL", i);
+        windows.push("MSFT", -i);
+        windows.push("TSLA", 100 + i);
+    }
+
+    assert_eq!(windows.slice(&"AAPL"), Ok(&[17, 18, 19][..]));
+    assert_eq!(windows.slice(&"MSFT"), Ok(&[-17, -18, -19][..]));
+    assert_eq!(windows.slice(&"TSLA"), Ok(&[117, 118, 119][..]));
+}
+
+#[test]
+fn test_iter() {
+    let mut windows = get_windows();
+    windows.push("AAPL", 1);
+    windows.push("MSFT", 2);
+    windows.push("MSFT", 3);
+
+    let all: HashMap<_, _> = windows.iter().map(|(k, w)| (*k, w.to_vec())).collect();
+    assert_eq!(all.len(), 2);
+    assert_eq!(all[&"AAPL"], vec![1]);
+    assert_eq!(all[&"MSFT"], vec![2, 3]);
+}
+
+#[test]
+fn test_remove_reuses_slot() {
+    let mut windows = get_windows();
+    windows.push("AAPL", 1);
+    windows.push("MSFT", 2);
+
+    assert_eq!(windows.remove(&"AAPL"), true);
+    assert_eq!(windows.remove(&"AAPL"), false);
+    assert_eq!(windows.get(&"AAPL"), None);
+
+    // the new key starts with an empty window in the freed slot
+    windows.push("TSLA", 3);
+    assert_eq!(windows.get(&"TSLA"), Some(&[3][..]));
+    assert_eq!(windows.get(&"MSFT"), Some(&[2][..]));
+
+    windows.push("AAPL", 4);
+    assert_eq!(windows.get(&"AAPL"), Some(&[4][..]));
+}
+
+#[test]
+fn test_expire() {
+    let mut windows = get_windows();
+    windows.push("AAPL", 1);
+    windows.clock().advance(secs(30));
+    windows.push("MSFT", 2);
+
+    windows.clock().advance(secs(29));
+    assert_eq!(windows.expire(), 0);
+
+    windows.clock().advance(secs(1));
+    assert_eq!(windows.expire(), 1);
+    assert_eq!(windows.contains_key(&"AAPL"), false);
+    assert_eq!(windows.contains_key(&"MSFT"), true);
+
+    // a push keeps the key alive
+    windows.push("MSFT", 3);
+    assert_eq!(windows.evict_idle(secs(100)), 0);
+    assert_eq!(windows.evict_idle(secs(120)), 1);
+    assert_eq!(windows.empty(), true);
+}
+
+#[test]
+fn test_many_keys() {
+    let mut windows: KeyedSlidingWindows<u32, u64, ManualClock> =
+        KeyedSlidingWindows::with_clock(SIZE, MULTIPLE, secs(60), ManualClock::new(secs(0))).unwrap();
+    windows.reserve(10_000);
+
+    for round in 0..5u64 {
+        for key in 0..10_000u32 {
+            windows.push(key, key as u64 * 10 + round);
+        }
+    }
+
+    assert_eq!(windows.len(), 10_000);
+    for key in [0, 1, 4_999, 9_999] {
+        let base = key as u64 * 10;
+        assert_eq!(windows.slice(&key), Ok(&[base + 2, base + 3, base + 4][..]));
+    }
+}
+
+#[test]
+fn test_invalid_configuration() {
+    let res = KeyedSlidingWindows::<&str, i32>::new(0, MULTIPLE, secs(60));
+    assert_eq!(res.err(), Some(SlidingWindowError::InvalidSize));
+
+    let res = KeyedSlidingWindows::<&str, i32>::new(SIZE, 1, secs(60));
+    assert_eq!(res.err(), Some(SlidingWindowError::InvalidMultiple { multiple: 1 }));
+
+    let res = KeyedSlidingWindows::<&str, u64>::new(usize::MAX / 16, 2, secs(60));
+    assert_eq!(res.err(), Some(SlidingWindowError::InvalidMultiple { multiple: 2 }));
+
+    let res = KeyedSlidingWindows::<&str, i32>::new(usize::MAX, MULTIPLE, secs(60));
+    assert_eq!(res.err(), Some(SlidingWindowError::InvalidMultiple { multiple: MULTIPLE }));
+}
@@ -0,0 +1,58 @@
+//! Model checks the memory ordering of the concurrent window.
+//!
+//! Run with `RUSTFLAGS="--cfg loom" cargo test --release --test loom_tests`.
+#![cfg(loom)]
+
+use loom::thread;
+
+use sliding_window::concurrent_window::new_concurrent_window;
+
+const SIZE: usize = 2;
+const CAPACITY: usize = 3;
+
+/// Checks that a copy of the window holds consecutive pushes.
+fn assert_consistent(arr: [u64; SIZE]) {
+    assert_eq!(arr[1], arr[0] + 1, "torn read: {:?}", arr);
+}
+
+#[test]
+fn test_read_during_push() {
+    loom::model(|| {
+        let (mut writer, reader) = new_concurrent_window::<u64, SIZE, CAPACITY>();
+        writer.push(1);
+        writer.push(2);
+
+        let handle = thread::spawn(move || {
+            if let Ok(arr) = reader.arr() {
+                assert_consistent(arr);
+            }
+        });
+
+        writer.push(3);
+        handle.join().unwrap();
+    });
+}
+
+#[test]
+fn test_read_during_rewind() {
+    let mut builder = loom::model::Builder::new();
+    builder.preemption_bound = Some(3);
+
+    builder.check(|| {
+        let (mut writer, reader) = new_concurrent_window::<u64, SIZE, CAPACITY>();
+        writer.push(1);
+        writer.push(2);
+        writer.push(3);
+
+        let handle = thread::spawn(move || {
+            let arr = reader.arr().unwrap();
+            assert_consistent(arr);
+            assert!(arr[1] >= 3);
+        });
+
+        // The buffer is full, so this push rewinds.
+        writer.push(4);
+        writer.push(5);
+        handle.join().unwrap();
+    });
+}
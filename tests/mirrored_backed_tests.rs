@@ -0,0 +1,120 @@
+#![allow(clippy::bool_assert_comparison)]
+
+use std::collections::VecDeque;
+
+use sliding_window::error::SlidingWindowError;
+use sliding_window::rolling_stats::RollingStats;
+use sliding_window::sliding_window::{new_with_mirrored_storage, SlidingWindow};
+use sliding_window::storage::Storage;
+use sliding_window::storage_mirrored::MirroredStorage;
+
+const SIZE: usize = 4;
+
+fn get_sliding_window() -> SlidingWindow<MirroredStorage<i32>, i32> {
+    new_with_mirrored_storage(SIZE)
+}
+
+#[test]
+fn test_empty() {
+    let window = get_sliding_window();
+    assert_eq!(window.empty(), true);
+    assert_eq!(window.filled(), false);
+    assert_eq!(window.size(), SIZE);
+    assert_eq!(window.len(), 0);
+    assert_eq!(window.first(), Err(SlidingWindowError::Empty));
+    assert_eq!(window.slice(), Err(SlidingWindowError::NotFilled { len: 0, size: SIZE }));
+}
+
+#[test]
+fn test_push_evict() {
+    let mut window = get_sliding_window();
+    for i in 0..SIZE as i32 {
+        assert_eq!(window.push_evict(i), None);
+        assert_eq!(window.len(), i as usize + 1);
+    }
+    assert_eq!(window.filled(), true);
+
+    assert_eq!(window.push_evict(4), Some(0));
+    assert_eq!(window.push_evict(5), Some(1));
+    assert_eq!(window.len(), SIZE);
+    assert_eq!(window.first(), Ok(2));
+    assert_eq!(window.last(), Ok(5));
+}
+
+#[test]
+fn test_slice() {
+    let mut window = get_sliding_window();
+    for i in 0..6 {
+        window.push(i);
+    }
+
+    // the window is always contiguous
+    assert_eq!(window.slice(), Ok(&[2, 3, 4, 5][..]));
+    assert_eq!(window.as_slices(), (&[2, 3, 4, 5][..], &[][..]));
+
+    window.push(6);
+    window.push(7);
+    assert_eq!(window.slice(), Ok(&[4, 5, 6, 7][..]));
+
+    window.push(8);
+    assert_eq!(window.slice(), Ok(&[5, 6, 7, 8][..]));
+}
+
+#[test]
+fn test_partial() {
+    let mut storage = MirroredStorage::new(SIZE);
+    storage.push(1);
+    storage.push(2);
+
+    assert_eq!(storage.get_slice(), &[1, 2]);
+    assert_eq!(storage.first(), Ok(1));
+    assert_eq!(storage.last(), Err(SlidingWindowError::NotFilled { len: 2, size: SIZE }));
+}
+
+#[test]
+fn test_access() {
+    let mut window = get_sliding_window();
+    for i in 0..7 {
+        window.push(i);
+    }
+
+    assert_eq!(window.iter().copied().collect::<Vec<_>>(), vec![3, 4, 5, 6]);
+    assert_eq!(window.iter_newest_first().copied().collect::<Vec<_>>(), vec![6, 5, 4, 3]);
+    assert_eq!(window.get(0), Ok(3));
+    assert_eq!(window.get(4), Err(SlidingWindowError::OutOfBounds { index: 4, len: SIZE }));
+    assert_eq!(window.get_back(0), Ok(6));
+    assert_eq!(window[2], 5);
+    assert_eq!(window.arr::<SIZE>(), Ok([3, 4, 5, 6]));
+    assert_eq!(window.vec(), Ok(vec![3, 4, 5, 6]));
+}
+
+#[test]
+fn test_matches_model() {
+    let mut window = get_sliding_window();
+    let mut model = VecDeque::new();
+
+    for i in 0..100 {
+        let evicted = window.push_evict(i);
+
+        model.push_back(i);
+        let expected = if model.len() > SIZE { model.pop_front() } else { None };
+
+        assert_eq!(evicted, expected);
+        assert_eq!(window.as_slices().0, model.make_contiguous());
+    }
+}
+
+#[test]
+fn test_rolling_stats() {
+    let mut window = get_sliding_window();
+    for i in 0..6 {
+        window.push(i);
+    }
+
+    let mut stats = RollingStats::new(window);
+    assert_eq!(stats.sum(), 14.0);
+
+    stats.push(6);
+    assert_eq!(stats.sum(), 18.0);
+    assert_eq!(stats.mean(), Ok(4.5));
+}
@@ -0,0 +1,137 @@
+#![cfg(target_os = "linux")]
+#![allow(clippy::bool_assert_comparison)]
+
+use std::collections::VecDeque;
+use std::io;
+
+use sliding_window::error::SlidingWindowError;
+use sliding_window::sliding_window::{new_with_mmap_storage, SlidingWindow};
+use sliding_window::storage::Storage;
+use sliding_window::storage_mmap::MmapStorage;
+
+const SIZE: usize = 4;
+
+fn get_sliding_window() -> SlidingWindow<MmapStorage<i32>, i32> {
+    new_with_mmap_storage(SIZE)
+}
+
+fn check_model(storage: &mut MmapStorage<i64>, pushes: i64) {
+    let size = storage.size();
+    let mut model = VecDeque::new();
+
+    for i in 0..pushes {
+        let evicted = storage.push_evict(i);
+
+        model.push_back(i);
+        let expected = if model.len() > size { model.pop_front() } else { None };
+
+        assert_eq!(evicted, expected);
+        assert_eq!(storage.get_slice(), model.make_contiguous());
+    }
+}
+
+#[test]
+fn test_mapped() {
+    let storage = MmapStorage::<i32>::try_new(SIZE).expect("memfd and mmap are available on Linux");
+    assert_eq!(storage.is_mapped(), true);
+    assert_eq!(storage.size(), SIZE);
+    assert_eq!(storage.empty(), true);
+}
+
+#[test]
+fn test_capacity_rounding() {
+    let page = 4096;
+
+    let storage = MmapStorage::<i32>::try_new(SIZE).unwrap();
+    assert_eq!(storage.capacity() * 4 % page, 0);
+    assert_eq!(storage.capacity() >= SIZE, true);
+
+    // an element size that does not divide the page size
+    let storage = MmapStorage::<[u8; 12]>::try_new(1000).unwrap();
+    assert_eq!(storage.capacity() * 12 % page, 0);
+    assert_eq!(storage.capacity() >= 1000, true);
+}
+
+#[test]
+fn test_invalid() {
+    let res = MmapStorage::<i32>::try_new(0);
+    assert_eq!(res.err().map(|e| e.kind()), Some(io::ErrorKind::InvalidInput));
+
+    let res = MmapStorage::<()>::try_new(SIZE);
+    assert_eq!(res.err().map(|e| e.kind()), Some(io::ErrorKind::InvalidInput));
+
+    let res = MmapStorage::<i64>::try_new(usize::MAX / 4);
+    assert_eq!(res.is_err(), true);
+}
+
+#[test]
+fn test_push_evict() {
+    let mut window = get_sliding_window();
+    for i in 0..SIZE as i32 {
+        assert_eq!(window.push_evict(i), None);
+    }
+    assert_eq!(window.filled(), true);
+
+    assert_eq!(window.push_evict(4), Some(0));
+    assert_eq!(window.push_evict(5), Some(1));
+    assert_eq!(window.first(), Ok(2));
+    assert_eq!(window.last(), Ok(5));
+    assert_eq!(window.slice(), Ok(&[2, 3, 4, 5][..]));
+}
+
+#[test]
+fn test_access() {
+    let mut window = get_sliding_window();
+    assert_eq!(window.slice(), Err(SlidingWindowError::NotFilled { len: 0, size: SIZE }));
+
+    for i in 0..7 {
+        window.push(i);
+    }
+
+    assert_eq!(window.as_slices(), (&[3, 4, 5, 6][..], &[][..]));
+    assert_eq!(window.iter_newest_first().copied().collect::<Vec<_>>(), vec![6, 5, 4, 3]);
+    assert_eq!(window.get(0), Ok(3));
+    assert_eq!(window.get_back(0), Ok(6));
+    assert_eq!(window[2], 5);
+    assert_eq!(window.arr::<SIZE>(), Ok([3, 4, 5, 6]));
+    assert_eq!(window.vec(), Ok(vec![3, 4, 5, 6]));
+}
+
+#[test]
+fn test_wrap_around() {
+    // the window wraps around the end of the ring several times
+    let mut storage = MmapStorage::try_new(100).unwrap();
+    let pushes = 3 * storage.capacity() as i64 + 17;
+    check_model(&mut storage, pushes);
+}
+
+#[test]
+fn test_window_equals_capacity() {
+    // a window of exactly one page spans into the mirror on every push but the first
+    let page = MmapStorage::<i64>::try_new(1).unwrap().capacity();
+    let mut storage = MmapStorage::try_new(page).unwrap();
+    assert_eq!(storage.capacity(), page);
+    check_model(&mut storage, 4 * page as i64);
+}
+
+#[test]
+fn test_unmapped() {
+    let mut storage = MmapStorage::new_unmapped(SIZE);
+    assert_eq!(storage.is_mapped(), false);
+    assert_eq!(storage.capacity(), SIZE);
+    check_model(&mut storage, 100);
+}
+
+#[test]
+fn test_large_window() {
+    let size = 1_000_000;
+    let mut storage = MmapStorage::new(size);
+    for i in 0..(size + size / 2) as i64 {
+        storage.push(i);
+    }
+
+    let slice = storage.get_slice();
+    assert_eq!(slice.len(), size);
+    assert_eq!(slice.first(), Some(&(size as i64 / 2)));
+    assert_eq!(slice.last(), Some(&(size as i64 + size as i64 / 2 - 1)));
+}
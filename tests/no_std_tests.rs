@@ -0,0 +1,13 @@
+use std::process::Command;
+
+/// Builds the `no_std_check` crate, which uses the array backed window from a `#![no_std]` library.
+#[test]
+fn test_no_std_build() {
+    let manifest = concat!(env!("CARGO_MANIFEST_DIR"), "/no_std_check/Cargo.toml");
+    let output = Command::new(env!("CARGO"))
+        .args(["build", "--offline", "--manifest-path", manifest])
+        .output()
+        .expect("Failed to run cargo");
+
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+}
@@ -0,0 +1,127 @@
+#![allow(clippy::bool_assert_comparison)]
+
+use std::rc::Rc;
+
+use sliding_window::error::SlidingWindowError;
+use sliding_window::sliding_window::{new_with_owned_storage, new_with_vector_storage_and_warm_up, SlidingWindow};
+use sliding_window::storage_owned::OwnedStorage;
+use sliding_window::warm_up::WarmUp;
+
+const SIZE: usize = 4;
+const MULT: usize = 2;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Order {
+    id: String,
+    qty: u32,
+}
+
+fn order(i: u32) -> Order {
+    Order { id: format!("order-{}", i), qty: i }
+}
+
+fn get_sliding_window() -> SlidingWindow<OwnedStorage<Order>, Order> {
+    new_with_owned_storage(SIZE, MULT)
+}
+
+#[test]
+fn test_empty() {
+    let mut window = get_sliding_window();
+    assert_eq!(window.empty(), true);
+    assert_eq!(window.first_ref(), Err(SlidingWindowError::Empty));
+
+    window.push(order(0));
+    assert_eq!(window.empty(), false);
+    assert_eq!(window.filled(), false);
+    assert_eq!(window.size(), SIZE);
+}
+
+#[test]
+fn test_first_last() {
+    let mut window = get_sliding_window();
+    for i in 0..SIZE as u32 {
+        window.push(order(i));
+    }
+    assert_eq!(window.filled(), true);
+    assert_eq!(window.first_ref().unwrap().id, "order-0");
+    assert_eq!(window.last_ref().unwrap().id, "order-3");
+    assert_eq!(window.first().unwrap(), order(0));
+    assert_eq!(window.last().unwrap(), order(3));
+}
+
+#[test]
+fn test_rewind() {
+    let mut window = get_sliding_window();
+
+    // Push well past the capacity of 8 to run through several rewinds.
+    for i in 0..40u32 {
+        let evicted = window.push_evict(order(i));
+        if i >= SIZE as u32 {
+            assert_eq!(evicted, Some(order(i - SIZE as u32)));
+        } else {
+            assert_eq!(evicted, None);
+        }
+
+        let first = i.saturating_sub(SIZE as u32 - 1);
+        let qty: Vec<u32> = window.iter().map(|o| o.qty).collect();
+        assert_eq!(qty, (first..=i).collect::<Vec<u32>>());
+    }
+
+    let vec = window.vec().expect("Failed to get vec");
+    assert_eq!(vec, vec![order(36), order(37), order(38), order(39)]);
+
+    let arr: [Order; SIZE] = window.arr().expect("Failed to get arr");
+    assert_eq!(arr[3], order(39));
+}
+
+#[test]
+fn test_drop_accounting() {
+    let tracker = Rc::new(());
+    {
+        let mut window = new_with_owned_storage(SIZE, MULT);
+        for _ in 0..25 {
+            window.push(Rc::clone(&tracker));
+            assert!(Rc::strong_count(&tracker) <= SIZE + 1);
+        }
+        assert_eq!(Rc::strong_count(&tracker), SIZE + 1);
+
+        // Evicted values are handed back and released by the caller.
+        let evicted = window.push_evict(Rc::clone(&tracker));
+        assert_eq!(Rc::strong_count(&tracker), SIZE + 2);
+        drop(evicted);
+        assert_eq!(Rc::strong_count(&tracker), SIZE + 1);
+    }
+    // Dropping the window drops all remaining elements exactly once.
+    assert_eq!(Rc::strong_count(&tracker), 1);
+}
+
+#[test]
+fn test_drop_partial() {
+    let tracker = Rc::new(());
+    {
+        let mut window = new_with_owned_storage(SIZE, MULT);
+        window.push(Rc::clone(&tracker));
+        window.push(Rc::clone(&tracker));
+        assert_eq!(Rc::strong_count(&tracker), 3);
+    }
+    assert_eq!(Rc::strong_count(&tracker), 1);
+}
+
+#[test]
+fn test_strings() {
+    let mut window = new_with_owned_storage(2, 3);
+    for line in ["a", "b", "c", "d", "e", "f", "g"] {
+        window.push(line.to_string());
+    }
+    assert_eq!(window.slice().unwrap(), &["f".to_string(), "g".to_string()]);
+    assert_eq!(window.lag(1), Ok("f".to_string()));
+    assert_eq!(&window[0], "f");
+}
+
+#[test]
+fn test_copy_backend_push_evict() {
+    let mut window = new_with_vector_storage_and_warm_up(2, MULT, WarmUp::Padded(0));
+    assert_eq!(window.push_evict(1), Some(0));
+    assert_eq!(window.push_evict(2), Some(0));
+    assert_eq!(window.push_evict(3), Some(1));
+}
@@ -0,0 +1,194 @@
+#![allow(clippy::bool_assert_comparison)]
+
+use std::collections::VecDeque;
+
+use sliding_window::builder::SlidingWindowBuilder;
+use sliding_window::rewind::Rewind;
+use sliding_window::sliding_window::{new_with_array_storage, new_with_array_storage_and_rewind, new_with_vector_storage, new_with_vector_storage_and_rewind, SlidingWindow};
+use sliding_window::storage::Storage;
+use sliding_window::warm_up::WarmUp;
+
+const SIZE: usize = 4;
+const MULT: usize = 3;
+const CAPACITY: usize = 12;
+
+fn check_model<S>(window: &mut SlidingWindow<S, i32>, from: i32, to: i32, model: &mut VecDeque<i32>)
+    where
+        S: Storage<i32>,
+{
+    for i in from..to {
+        let evicted = window.push_evict(i);
+
+        model.push_back(i);
+        let expected = if model.len() > window.size() { model.pop_front() } else { None };
+
+        assert_eq!(evicted, expected);
+        assert_eq!(window.as_slices().0, model.make_contiguous());
+    }
+}
+
+#[test]
+fn test_default() {
+    assert_eq!(Rewind::default(), Rewind::Full);
+    assert_eq!(new_with_vector_storage::<i32>(SIZE, MULT).rewind(), Rewind::Full);
+    assert_eq!(new_with_array_storage::<i32, SIZE, CAPACITY>().rewind(), Rewind::Full);
+}
+
+#[test]
+fn test_vector_incremental() {
+    let mut window = new_with_vector_storage_and_rewind(SIZE, MULT, Rewind::Incremental);
+    assert_eq!(window.rewind(), Rewind::Incremental);
+    check_model(&mut window, 0, 100, &mut VecDeque::new());
+}
+
+#[test]
+fn test_array_incremental() {
+    let mut window = new_with_array_storage_and_rewind::<i32, SIZE, CAPACITY>(Rewind::Incremental);
+    assert_eq!(window.rewind(), Rewind::Incremental);
+    check_model(&mut window, 0, 100, &mut VecDeque::new());
+}
+
+#[test]
+fn test_minimal_capacity() {
+    // the smallest capacity at which the front does not overlap the window
+    let mut window = new_with_array_storage_and_rewind::<i32, SIZE, 7>(Rewind::Incremental);
+    check_model(&mut window, 0, 100, &mut VecDeque::new());
+
+    let mut window = new_with_vector_storage_and_rewind(SIZE, 2, Rewind::Incremental);
+    check_model(&mut window, 0, 100, &mut VecDeque::new());
+}
+
+#[test]
+fn test_small_capacity_falls_back() {
+    let mut window = new_with_array_storage_and_rewind::<i32, SIZE, 5>(Rewind::Incremental);
+    check_model(&mut window, 0, 100, &mut VecDeque::new());
+
+    let mut window = new_with_vector_storage_and_rewind(SIZE, 1, Rewind::Incremental);
+    check_model(&mut window, 0, 100, &mut VecDeque::new());
+}
+
+#[test]
+fn test_size_one() {
+    let mut window = new_with_array_storage_and_rewind::<i32, 1, 4>(Rewind::Incremental);
+    check_model(&mut window, 0, 20, &mut VecDeque::new());
+
+    let mut window = new_with_vector_storage_and_rewind(1, MULT, Rewind::Incremental);
+    check_model(&mut window, 0, 20, &mut VecDeque::new());
+}
+
+#[test]
+fn test_set_rewind() {
+    // switch at every position of the tail between two rewinds
+    for switch in 0..2 * CAPACITY as i32 {
+        let mut model = VecDeque::new();
+        let mut array = new_with_array_storage::<i32, SIZE, CAPACITY>();
+        check_model(&mut array, 0, switch, &mut model);
+        array.set_rewind(Rewind::Incremental);
+        check_model(&mut array, switch, 100, &mut model);
+
+        let mut model = VecDeque::new();
+        let mut vector = new_with_vector_storage(SIZE, MULT);
+        check_model(&mut vector, 0, switch, &mut model);
+        vector.set_rewind(Rewind::Incremental);
+        check_model(&mut vector, switch, 100, &mut model);
+        vector.set_rewind(Rewind::Full);
+        assert_eq!(vector.rewind(), Rewind::Full);
+        check_model(&mut vector, 100, 150, &mut model);
+    }
+}
+
+#[test]
+fn test_vector_resize() {
+    let mut history = Vec::new();
+    let mut window = new_with_vector_storage_and_rewind(SIZE, MULT, Rewind::Incremental);
+
+    for (size, multiple) in [(SIZE, MULT), (6, MULT), (6, 2), (3, 2), (5, 2), (5, 4)] {
+        window.resize(size).unwrap();
+        window.set_multiple(multiple).unwrap();
+        assert_eq!(window.rewind(), Rewind::Incremental);
+
+        // growing may restore evicted elements, which must be the ones pushed before the window
+        let len = window.len();
+        assert_eq!(window.as_slices().0, &history[history.len() - len..]);
+
+        for i in 0..50 {
+            window.push(i);
+            history.push(i);
+
+            let len = window.len();
+            assert_eq!(len <= size, true);
+            assert_eq!(window.as_slices().0, &history[history.len() - len..]);
+        }
+        assert_eq!(window.len(), size);
+    }
+}
+
+#[test]
+fn test_vector_resize_after_set_rewind() {
+    // the incremental rewind has overwritten the evicted element 0 with 4
+    let mut window = new_with_vector_storage_and_rewind::<i64>(3, 2, Rewind::Incremental);
+    for i in 0..5 {
+        window.push(i);
+    }
+
+    window.set_rewind(Rewind::Full);
+    window.resize(5).unwrap();
+    assert_eq!(window.iter().copied().collect::<Vec<i64>>(), vec![1, 2, 3, 4]);
+}
+
+#[test]
+fn test_vector_resize_switching_rewind() {
+    // deterministic pseudo random sequence of pushes, rewind mode changes and resizes
+    let mut seed: u64 = 42;
+    let mut next = |n: u64| {
+        seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        (seed >> 33) % n
+    };
+
+    for _ in 0..100 {
+        let mut history = Vec::new();
+        let mut window = new_with_vector_storage_and_rewind(1 + next(6) as usize, 2 + next(3) as usize, Rewind::Incremental);
+
+        for i in 0..200 {
+            match next(20) {
+                0 => window.set_rewind(if next(2) == 0 { Rewind::Full } else { Rewind::Incremental }),
+                1 => window.resize(1 + next(8) as usize).unwrap(),
+                _ => {
+                    window.push(i);
+                    history.push(i);
+                }
+            }
+
+            // a resize only ever restores elements that were pushed before the window
+            let len = window.len();
+            assert_eq!(window.as_slices().0, &history[history.len() - len..]);
+        }
+    }
+}
+
+#[test]
+fn test_vector_resize_padded() {
+    let mut window = SlidingWindowBuilder::new().size(SIZE).multiple(MULT).rewind(Rewind::Incremental).warm_up(WarmUp::Padded(0)).build().unwrap();
+    for i in 1..=3 {
+        window.push(i);
+    }
+
+    // growing a padded window beyond its history pads it again, and keeps the rewind mode
+    window.resize(9).unwrap();
+    assert_eq!(window.rewind(), Rewind::Incremental);
+    assert_eq!(window.slice(), Ok(&[0, 0, 0, 0, 0, 0, 1, 2, 3][..]));
+
+    let mut model = VecDeque::from(vec![0, 0, 0, 0, 0, 0, 1, 2, 3]);
+    check_model(&mut window, 4, 100, &mut model);
+}
+
+#[test]
+fn test_builder() {
+    let mut window = SlidingWindowBuilder::new().size(SIZE).multiple(MULT).rewind(Rewind::Incremental).build().unwrap();
+    assert_eq!(window.rewind(), Rewind::Incremental);
+    check_model(&mut window, 0, 100, &mut VecDeque::new());
+
+    let mut window = SlidingWindowBuilder::new().rewind(Rewind::Incremental).build_array::<SIZE, CAPACITY>().unwrap();
+    assert_eq!(window.rewind(), Rewind::Incremental);
+    check_model(&mut window, 0, 100, &mut VecDeque::new());
+}
@@ -0,0 +1,167 @@
+#![allow(clippy::bool_assert_comparison)]
+
+use std::collections::VecDeque;
+
+use sliding_window::error::SlidingWindowError;
+use sliding_window::rolling_stats::RollingStats;
+use sliding_window::sliding_window::{new_with_array_storage, new_with_ring_storage, new_with_vector_storage, SlidingWindow};
+use sliding_window::storage::Storage;
+use sliding_window::storage_ring::RingStorage;
+
+const SIZE: usize = 4;
+
+fn get_sliding_window() -> SlidingWindow<RingStorage<i32>, i32> {
+    new_with_ring_storage(SIZE)
+}
+
+#[test]
+fn test_empty() {
+    let window = get_sliding_window();
+    assert_eq!(window.empty(), true);
+    assert_eq!(window.size(), SIZE);
+    assert_eq!(window.len(), 0);
+    assert_eq!(window.first(), Err(SlidingWindowError::Empty));
+    assert_eq!(window.last(), Err(SlidingWindowError::NotFilled { len: 0, size: SIZE }));
+    assert_eq!(window.as_slices(), (&[][..], &[][..]));
+}
+
+#[test]
+fn test_push_evict() {
+    let mut window = get_sliding_window();
+    for i in 0..SIZE as i32 {
+        assert_eq!(window.push_evict(i), None);
+    }
+    assert_eq!(window.filled(), true);
+
+    assert_eq!(window.push_evict(4), Some(0));
+    assert_eq!(window.push_evict(5), Some(1));
+    assert_eq!(window.first(), Ok(2));
+    assert_eq!(window.last(), Ok(5));
+}
+
+#[test]
+fn test_as_slices() {
+    let mut window = get_sliding_window();
+    for i in 0..6 {
+        window.push(i);
+    }
+
+    assert_eq!(window.as_slices(), (&[2, 3][..], &[4, 5][..]));
+    assert_eq!(window.slice(), Err(SlidingWindowError::NotContiguous));
+
+    // the full buffer is contiguous again after every SIZE pushes
+    window.push(6);
+    window.push(7);
+    assert_eq!(window.as_slices(), (&[4, 5, 6, 7][..], &[][..]));
+    assert_eq!(window.slice(), Ok(&[4, 5, 6, 7][..]));
+}
+
+#[test]
+fn test_get_slice_wrapped() {
+    let mut storage = RingStorage::new(SIZE);
+    for i in 0..6 {
+        storage.push(i);
+    }
+
+    // a wrapped window returns the part up to the wrap instead of panicking
+    assert_eq!(storage.get_slice(), &[2, 3]);
+    assert_eq!(storage.as_slices(), (&[2, 3][..], &[4, 5][..]));
+    assert_eq!(storage.slice(), Err(SlidingWindowError::NotContiguous));
+
+    assert_eq!(storage.make_contiguous(), &[2, 3, 4, 5]);
+    assert_eq!(storage.get_slice(), &[2, 3, 4, 5]);
+}
+
+#[test]
+fn test_make_contiguous() {
+    let mut window = get_sliding_window();
+    for i in 0..7 {
+        window.push(i);
+    }
+
+    assert_eq!(window.make_contiguous(), &[3, 4, 5, 6]);
+    assert_eq!(window.as_slices(), (&[3, 4, 5, 6][..], &[][..]));
+    assert_eq!(window.slice(), Ok(&[3, 4, 5, 6][..]));
+
+    window.push(7);
+    assert_eq!(window.as_slices(), (&[4, 5, 6][..], &[7][..]));
+}
+
+#[test]
+fn test_access() {
+    let mut window = get_sliding_window();
+    for i in 0..7 {
+        window.push(i);
+    }
+
+    assert_eq!(window.iter().copied().collect::<Vec<_>>(), vec![3, 4, 5, 6]);
+    assert_eq!(window.iter_newest_first().copied().collect::<Vec<_>>(), vec![6, 5, 4, 3]);
+    assert_eq!(window.iter().len(), SIZE);
+    assert_eq!(window.iter().nth(2), Some(&5));
+    assert_eq!(window.iter().nth_back(2), Some(&4));
+    assert_eq!(window.get(0), Ok(3));
+    assert_eq!(window.get(3), Ok(6));
+    assert_eq!(window.get(4), Err(SlidingWindowError::OutOfBounds { index: 4, len: SIZE }));
+    assert_eq!(window.get_back(0), Ok(6));
+    assert_eq!(window.lag(3), Ok(3));
+    assert_eq!(window.diff(2), Ok(2));
+    assert_eq!(window[1], 4);
+    assert_eq!(window.first_ref(), Ok(&3));
+    assert_eq!(window.last_ref(), Ok(&6));
+    assert_eq!(window.arr::<SIZE>(), Ok([3, 4, 5, 6]));
+    assert_eq!(window.vec(), Ok(vec![3, 4, 5, 6]));
+}
+
+#[test]
+fn test_matches_model() {
+    let mut window = get_sliding_window();
+    let mut model = VecDeque::new();
+
+    for i in 0..100 {
+        let evicted = window.push_evict(i);
+
+        model.push_back(i);
+        let expected = if model.len() > SIZE { model.pop_front() } else { None };
+
+        assert_eq!(evicted, expected);
+        assert_eq!(window.iter().copied().collect::<VecDeque<_>>(), model);
+    }
+}
+
+#[test]
+fn test_rolling_stats() {
+    let mut window = get_sliding_window();
+    for i in 0..6 {
+        window.push(i);
+    }
+
+    let mut stats = RollingStats::new(window);
+    assert_eq!(stats.sum(), 14.0);
+
+    stats.push(6);
+    assert_eq!(stats.sum(), 18.0);
+    assert_eq!(stats.mean(), Ok(4.5));
+}
+
+#[test]
+fn test_contiguous_backends() {
+    let mut array = new_with_array_storage::<i32, SIZE, 8>();
+    let mut vector = new_with_vector_storage(SIZE, 2);
+    for i in 0..11 {
+        array.push(i);
+        vector.push(i);
+    }
+
+    assert_eq!(array.as_slices(), (&[7, 8, 9, 10][..], &[][..]));
+    assert_eq!(vector.as_slices(), (&[7, 8, 9, 10][..], &[][..]));
+    assert_eq!(array.make_contiguous(), &[7, 8, 9, 10]);
+    assert_eq!(vector.make_contiguous(), &[7, 8, 9, 10]);
+}
+
+#[test]
+fn test_error_display() {
+    assert_eq!(
+        SlidingWindowError::NotContiguous.to_string(),
+        "Sliding window is not contiguous. Call make_contiguous first"
+    );
+}
@@ -0,0 +1,111 @@
+use sliding_window::error::SlidingWindowError;
+use sliding_window::rolling_min_max::RollingMinMax;
+use sliding_window::sliding_window::{new_with_array_storage, new_with_vector_storage};
+use sliding_window::storage::Storage;
+
+const SIZE: usize = 4;
+const MULT: usize = 2;
+const CAPACITY: usize = 8;
+
+fn check<S: Storage<i32>>(tracker: &mut RollingMinMax<S, i32>, values: &[i32]) {
+    let mut history: Vec<i32> = Vec::new();
+    for &v in values {
+        tracker.push(v);
+        history.push(v);
+
+        let start = history.len().saturating_sub(SIZE);
+        let window = &history[start..];
+        let min = *window.iter().min().unwrap();
+        let max = *window.iter().max().unwrap();
+        assert_eq!(tracker.min(), Ok(min));
+        assert_eq!(tracker.max(), Ok(max));
+        assert_eq!(window[tracker.min_index().unwrap()], min);
+        assert_eq!(window[tracker.max_index().unwrap()], max);
+    }
+}
+
+fn values() -> Vec<i32> {
+    (0..100).map(|i| (i * 37) % 11 - 5).collect()
+}
+
+#[test]
+fn test_empty() {
+    let tracker = RollingMinMax::new(new_with_vector_storage::<i32>(SIZE, MULT));
+    assert_eq!(tracker.min(), Err(SlidingWindowError::Empty));
+    assert_eq!(tracker.max(), Err(SlidingWindowError::Empty));
+    assert_eq!(tracker.min_index(), Err(SlidingWindowError::Empty));
+    assert_eq!(tracker.max_index(), Err(SlidingWindowError::Empty));
+}
+
+#[test]
+fn test_vector_backed() {
+    let mut tracker = RollingMinMax::new(new_with_vector_storage(SIZE, MULT));
+    check(&mut tracker, &values());
+}
+
+#[test]
+fn test_array_backed() {
+    let mut tracker = RollingMinMax::new(new_with_array_storage::<i32, SIZE, CAPACITY>());
+    check(&mut tracker, &values());
+}
+
+#[test]
+fn test_positions() {
+    let mut tracker = RollingMinMax::new(new_with_vector_storage(SIZE, MULT));
+    tracker.push(3);
+    tracker.push(1);
+    tracker.push(7);
+    assert_eq!(tracker.min_index(), Ok(1));
+    assert_eq!(tracker.max_index(), Ok(2));
+
+    tracker.push(1);
+    // Ties report the newest position.
+    assert_eq!(tracker.min_index(), Ok(3));
+
+    tracker.push(0);
+    tracker.push(0);
+    tracker.push(0);
+    // 7 has been evicted.
+    assert_eq!(tracker.max(), Ok(1));
+    assert_eq!(tracker.max_index(), Ok(0));
+}
+
+#[test]
+fn test_seeded_window() {
+    let mut window = new_with_vector_storage(SIZE, MULT);
+    window.push(5);
+    window.push(9);
+
+    let tracker = RollingMinMax::new(window);
+    assert_eq!(tracker.min(), Ok(5));
+    assert_eq!(tracker.max(), Ok(9));
+    assert_eq!(tracker.max_index(), Ok(1));
+}
+
+#[test]
+fn test_nan_ignored() {
+    let mut tracker = RollingMinMax::new(new_with_vector_storage(SIZE, MULT));
+    tracker.push(f64::NAN);
+    assert_eq!(tracker.min(), Err(SlidingWindowError::Empty));
+
+    tracker.push(2.0);
+    tracker.push(f64::NAN);
+    tracker.push(-1.0);
+    assert_eq!(tracker.min(), Ok(-1.0));
+    assert_eq!(tracker.max(), Ok(2.0));
+    assert_eq!(tracker.max_index(), Ok(1));
+}
+
+#[test]
+fn test_without_default() {
+    // an element type that implements neither Default nor Ord
+    #[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+    struct Price(f32);
+
+    let mut tracker = RollingMinMax::new(new_with_vector_storage(SIZE, MULT));
+    for p in [3.0, 1.0, 4.0, 1.5, 5.0] {
+        tracker.push(Price(p));
+    }
+    assert_eq!(tracker.min(), Ok(Price(1.0)));
+    assert_eq!(tracker.max(), Ok(Price(5.0)));
+}
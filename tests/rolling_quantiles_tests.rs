@@ -0,0 +1,114 @@
+use sliding_window::error::SlidingWindowError;
+use sliding_window::rolling_quantiles::RollingQuantiles;
+use sliding_window::sliding_window::{new_with_array_storage, new_with_vector_storage};
+use sliding_window::storage::Storage;
+
+const SIZE: usize = 5;
+const MULT: usize = 2;
+const CAPACITY: usize = 10;
+
+fn naive_quantile(window: &[i64], q: f64) -> f64 {
+    let mut sorted = window.to_vec();
+    sorted.sort();
+    let pos = q * (sorted.len() - 1) as f64;
+    let lo = pos.floor() as usize;
+    let hi = pos.ceil() as usize;
+    sorted[lo] as f64 + (sorted[hi] - sorted[lo]) as f64 * (pos - lo as f64)
+}
+
+fn check<S: Storage<i64>>(quantiles: &mut RollingQuantiles<S, i64>, values: &[i64]) {
+    let mut history: Vec<i64> = Vec::new();
+    for &v in values {
+        quantiles.push(v);
+        history.push(v);
+
+        let start = history.len().saturating_sub(SIZE);
+        let window = &history[start..];
+        assert_eq!(quantiles.count(), window.len());
+        assert_eq!(quantiles.median(), Ok(naive_quantile(window, 0.5)));
+        for q in [0.0, 0.1, 0.25, 0.9, 0.99, 1.0] {
+            let expected = naive_quantile(window, q);
+            assert!((quantiles.quantile(q).unwrap() - expected).abs() < 1e-9);
+        }
+        assert_eq!(quantiles.rank(v), window.iter().filter(|&&x| x < v).count());
+    }
+}
+
+fn values() -> Vec<i64> {
+    (0..200).map(|i| (i * 37) % 23 - 11).collect()
+}
+
+#[test]
+fn test_empty() {
+    let quantiles = RollingQuantiles::new(new_with_vector_storage::<i64>(SIZE, MULT));
+    assert_eq!(quantiles.count(), 0);
+    assert_eq!(quantiles.median(), Err(SlidingWindowError::Empty));
+    assert_eq!(quantiles.select(0), Err(SlidingWindowError::Empty));
+    assert_eq!(quantiles.rank(42), 0);
+}
+
+#[test]
+fn test_invalid_quantile() {
+    let mut quantiles = RollingQuantiles::new(new_with_vector_storage(SIZE, MULT));
+    quantiles.push(1);
+    assert_eq!(quantiles.quantile(1.5), Err(SlidingWindowError::InvalidQuantile));
+    assert_eq!(quantiles.quantile(-0.1), Err(SlidingWindowError::InvalidQuantile));
+    assert_eq!(quantiles.quantile(f64::NAN), Err(SlidingWindowError::InvalidQuantile));
+    assert_eq!(quantiles.select(1), Err(SlidingWindowError::OutOfBounds { index: 1, len: 1 }));
+}
+
+#[test]
+fn test_vector_backed() {
+    let mut quantiles = RollingQuantiles::new(new_with_vector_storage(SIZE, MULT));
+    check(&mut quantiles, &values());
+}
+
+#[test]
+fn test_array_backed() {
+    let mut quantiles = RollingQuantiles::new(new_with_array_storage::<i64, SIZE, CAPACITY>());
+    check(&mut quantiles, &values());
+}
+
+#[test]
+fn test_float_median() {
+    let mut quantiles = RollingQuantiles::new(new_with_vector_storage(4, MULT));
+    quantiles.push(1.0);
+    quantiles.push(4.0);
+    quantiles.push(2.0);
+    quantiles.push(3.0);
+    assert_eq!(quantiles.median(), Ok(2.5));
+    assert_eq!(quantiles.select(0), Ok(1.0));
+    assert_eq!(quantiles.select(3), Ok(4.0));
+}
+
+#[test]
+fn test_nan_excluded() {
+    let mut quantiles = RollingQuantiles::new(new_with_vector_storage(3, MULT));
+    quantiles.push(1.0);
+    quantiles.push(f64::NAN);
+    quantiles.push(3.0);
+    assert_eq!(quantiles.count(), 2);
+    assert_eq!(quantiles.median(), Ok(2.0));
+
+    // Evicting 1.0 and then the NaN keeps the statistics consistent.
+    quantiles.push(5.0);
+    assert_eq!(quantiles.median(), Ok(4.0));
+    quantiles.push(7.0);
+    assert_eq!(quantiles.count(), 3);
+    assert_eq!(quantiles.median(), Ok(5.0));
+}
+
+#[test]
+fn test_large_window() {
+    let size = 1001;
+    let mut quantiles = RollingQuantiles::new(new_with_vector_storage(size, 4));
+    for i in 0..10_000u64 {
+        quantiles.push((i * 7919) % 10_007);
+    }
+    assert_eq!(quantiles.count(), size);
+
+    let mut window = quantiles.window().vec().unwrap();
+    window.sort();
+    assert_eq!(quantiles.median(), Ok(window[size / 2] as f64));
+    assert_eq!(quantiles.select(size - 1), Ok(window[size - 1]));
+}
@@ -0,0 +1,179 @@
+#![allow(clippy::bool_assert_comparison)]
+
+use sliding_window::error::SlidingWindowError;
+use sliding_window::rolling_stats::RollingStats;
+use sliding_window::sliding_window::{new_with_array_storage, new_with_vector_storage};
+use sliding_window::storage::Storage;
+
+const SIZE: usize = 4;
+const MULT: usize = 2;
+const CAPACITY: usize = 8;
+
+fn naive(slice: &[f64]) -> (f64, f64, f64) {
+    let n = slice.len() as f64;
+    let sum: f64 = slice.iter().sum();
+    let mean = sum / n;
+    let var = slice.iter().map(|x| (x - mean) * (x - mean)).sum::<f64>() / n;
+    (sum, mean, var)
+}
+
+fn assert_close(a: f64, b: f64) {
+    assert!((a - b).abs() < 1e-9, "{} != {}", a, b);
+}
+
+fn assert_same(a: f64, b: f64) {
+    assert!(a == b || (a.is_nan() && b.is_nan()), "{} != {}", a, b);
+}
+
+fn check<S: Storage<f64>>(stats: &mut RollingStats<S, f64>, values: &[f64]) {
+    let mut history: Vec<f64> = Vec::new();
+    for &v in values {
+        stats.push(v);
+        history.push(v);
+
+        let start = history.len().saturating_sub(SIZE);
+        let (sum, mean, var) = naive(&history[start..]);
+        assert_eq!(stats.count(), history.len() - start);
+        assert_close(stats.sum(), sum);
+        assert_close(stats.mean().unwrap(), mean);
+        assert_close(stats.variance().unwrap(), var);
+        assert_close(stats.std_dev().unwrap(), var.sqrt());
+    }
+}
+
+fn values() -> Vec<f64> {
+    (0..100).map(|i| ((i * 37) % 11) as f64 * 1.5 - 4.0).collect()
+}
+
+#[test]
+fn test_empty() {
+    let stats = RollingStats::new(new_with_vector_storage::<f64>(SIZE, MULT));
+    assert_eq!(stats.count(), 0);
+    assert_eq!(stats.sum(), 0.0);
+    assert_eq!(stats.mean(), Err(SlidingWindowError::Empty));
+    assert_eq!(stats.variance(), Err(SlidingWindowError::Empty));
+}
+
+#[test]
+fn test_partial() {
+    let mut stats = RollingStats::new(new_with_array_storage::<i32, SIZE, CAPACITY>());
+    stats.push(2);
+    stats.push(4);
+    assert_eq!(stats.window().filled(), false);
+    assert_eq!(stats.count(), 2);
+    assert_eq!(stats.sum(), 6.0);
+    assert_eq!(stats.mean(), Ok(3.0));
+    assert_eq!(stats.variance(), Ok(1.0));
+}
+
+#[test]
+fn test_vector_backed() {
+    let mut stats = RollingStats::new(new_with_vector_storage(SIZE, MULT));
+    check(&mut stats, &values());
+}
+
+#[test]
+fn test_array_backed() {
+    let mut stats = RollingStats::new(new_with_array_storage::<f64, SIZE, CAPACITY>());
+    check(&mut stats, &values());
+}
+
+#[test]
+fn test_seeded_window() {
+    let mut window = new_with_vector_storage(SIZE, MULT);
+    window.push(1.0);
+    window.push(2.0);
+    window.push(3.0);
+
+    let mut stats = RollingStats::new(window);
+    assert_eq!(stats.count(), 3);
+    assert_eq!(stats.mean(), Ok(2.0));
+
+    stats.push(4.0);
+    stats.push(5.0);
+    assert_eq!(stats.count(), SIZE);
+    assert_eq!(stats.sum(), 14.0);
+}
+
+#[test]
+fn test_no_drift() {
+    let mut stats = RollingStats::new(new_with_vector_storage(SIZE, MULT));
+    for i in 0..1_000_000 {
+        stats.push(1e9 + (i % 3) as f64 * 0.1);
+    }
+    // The last four values are 1e9 + {0.0, 0.1, 0.2, 0.0} in some rotation.
+    let window = stats.window().vec().unwrap();
+    let (sum, mean, var) = naive(&window);
+    assert!((stats.sum() - sum).abs() < 1e-4);
+    assert!((stats.mean().unwrap() - mean).abs() < 1e-6);
+    assert!((stats.variance().unwrap() - var).abs() < 1e-6);
+}
+
+#[test]
+fn test_evict_outlier() {
+    for outlier in [1e8, 1e10, 1e12, 1e15] {
+        let mut stats = RollingStats::new(new_with_vector_storage(3, MULT));
+        stats.push(1.0);
+        stats.push(outlier);
+        for v in [7.0, 8.0, 9.0] {
+            stats.push(v);
+        }
+
+        assert_close(stats.sum(), 24.0);
+        assert_close(stats.mean().unwrap(), 8.0);
+        assert_close(stats.variance().unwrap(), 2.0 / 3.0);
+    }
+}
+
+#[test]
+fn test_evict_non_finite() {
+    for bad in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+        let mut stats = RollingStats::new(new_with_array_storage::<f64, SIZE, CAPACITY>());
+        stats.push(1.0);
+        stats.push(bad);
+        stats.push(2.0);
+
+        assert_eq!(stats.count(), 3);
+        assert_same(stats.sum(), 1.0 + bad + 2.0);
+        assert_same(stats.mean().unwrap(), (1.0 + bad + 2.0) / 3.0);
+        assert_eq!(stats.variance().unwrap().is_nan(), true);
+
+        // the non-finite value is evicted by the fourth push after it
+        for v in [3.0, 4.0, 5.0] {
+            stats.push(v);
+        }
+        assert_close(stats.sum(), 14.0);
+        assert_close(stats.mean().unwrap(), 3.5);
+        assert_close(stats.variance().unwrap(), 1.25);
+    }
+
+    let mut stats = RollingStats::new(new_with_array_storage::<f64, SIZE, CAPACITY>());
+    stats.push(f64::INFINITY);
+    stats.push(f64::NEG_INFINITY);
+    assert_eq!(stats.sum().is_nan(), true);
+}
+
+#[test]
+fn test_non_finite_stream() {
+    let mut values = values();
+    values[10] = f64::NAN;
+    values[30] = f64::INFINITY;
+    values[31] = f64::NEG_INFINITY;
+
+    let mut stats = RollingStats::new(new_with_vector_storage(SIZE, MULT));
+    for (i, &v) in values.iter().enumerate() {
+        stats.push(v);
+
+        let window = &values[(i + 1).saturating_sub(SIZE)..=i];
+        let (sum, mean, var) = naive(window);
+        if window.iter().all(|v| v.is_finite()) {
+            assert_close(stats.sum(), sum);
+            assert_close(stats.mean().unwrap(), mean);
+            assert_close(stats.variance().unwrap(), var);
+        } else {
+            assert_same(stats.sum(), sum);
+            assert_same(stats.mean().unwrap(), mean);
+            assert_eq!(stats.variance().unwrap().is_nan(), true);
+        }
+    }
+}
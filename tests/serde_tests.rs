@@ -0,0 +1,137 @@
+#![allow(clippy::bool_assert_comparison)]
+
+use sliding_window::rewind::Rewind;
+use sliding_window::sliding_window::{new_with_array_storage, new_with_array_storage_and_rewind, new_with_vector_storage, new_with_vector_storage_and_rewind, new_with_vector_storage_and_warm_up, SlidingWindow};
+use sliding_window::storage_array::ArrayStorage;
+use sliding_window::storage_vec::VectorStorage;
+use sliding_window::warm_up::WarmUp;
+
+const SIZE: usize = 4;
+const MULT: usize = 2;
+const CAPACITY: usize = 8;
+
+#[test]
+fn test_vector_round_trip() {
+    let mut window = new_with_vector_storage(SIZE, MULT);
+    for i in 0..11 {
+        window.push(i);
+    }
+
+    let json = serde_json::to_string(&window).unwrap();
+    assert_eq!(json, r#"{"storage":{"size":4,"multiple":2,"rewind":"Full","elements":[7,8,9,10]},"warm_up":"Strict"}"#);
+
+    let mut restored: SlidingWindow<VectorStorage<i32>, i32> = serde_json::from_str(&json).unwrap();
+    assert_eq!(restored.size(), SIZE);
+    assert_eq!(restored.vec().unwrap(), window.vec().unwrap());
+
+    // The restored window keeps sliding like the original.
+    for i in 11..30 {
+        window.push(i);
+        restored.push(i);
+        assert_eq!(restored.vec().unwrap(), window.vec().unwrap());
+    }
+}
+
+#[test]
+fn test_array_round_trip() {
+    let mut window = new_with_array_storage::<i32, SIZE, CAPACITY>();
+    window.push(1);
+    window.push(2);
+
+    let json = serde_json::to_string(&window).unwrap();
+    assert_eq!(json, r#"{"storage":{"size":4,"capacity":8,"rewind":"Full","elements":[1,2]},"warm_up":"Strict"}"#);
+
+    let restored: SlidingWindow<ArrayStorage<i32, SIZE, CAPACITY>, i32> = serde_json::from_str(&json).unwrap();
+    assert_eq!(restored.filled(), false);
+    assert_eq!(restored.iter().copied().collect::<Vec<i32>>(), vec![1, 2]);
+}
+
+#[test]
+fn test_warm_up_round_trip() {
+    let mut window = new_with_vector_storage_and_warm_up(SIZE, MULT, WarmUp::Padded(0));
+    window.push(5);
+
+    let json = serde_json::to_string(&window).unwrap();
+    let restored: SlidingWindow<VectorStorage<i32>, i32> = serde_json::from_str(&json).unwrap();
+    assert_eq!(restored.warm_up(), &WarmUp::Padded(0));
+    assert_eq!(restored.vec().unwrap(), vec![0, 0, 0, 5]);
+}
+
+#[test]
+fn test_rewind_round_trip() {
+    let mut vector = new_with_vector_storage_and_rewind(SIZE, MULT, Rewind::Incremental);
+    let mut array = new_with_array_storage_and_rewind::<i32, SIZE, CAPACITY>(Rewind::Incremental);
+    for i in 0..7 {
+        vector.push(i);
+        array.push(i);
+    }
+
+    let json = serde_json::to_string(&vector).unwrap();
+    let mut restored_vector: SlidingWindow<VectorStorage<i32>, i32> = serde_json::from_str(&json).unwrap();
+    assert_eq!(restored_vector.rewind(), Rewind::Incremental);
+
+    let json = serde_json::to_string(&array).unwrap();
+    let mut restored_array: SlidingWindow<ArrayStorage<i32, SIZE, CAPACITY>, i32> = serde_json::from_str(&json).unwrap();
+    assert_eq!(restored_array.rewind(), Rewind::Incremental);
+
+    for i in 7..30 {
+        vector.push(i);
+        array.push(i);
+        restored_vector.push(i);
+        restored_array.push(i);
+        assert_eq!(restored_vector.vec().unwrap(), vector.vec().unwrap());
+        assert_eq!(restored_array.vec().unwrap(), array.vec().unwrap());
+    }
+}
+
+#[test]
+fn test_rewind_defaults_to_full() {
+    let json = r#"{"storage":{"size":4,"multiple":2,"elements":[7,8,9,10]},"warm_up":"Strict"}"#;
+    let restored: SlidingWindow<VectorStorage<i32>, i32> = serde_json::from_str(json).unwrap();
+    assert_eq!(restored.vec().unwrap(), vec![7, 8, 9, 10]);
+}
+
+#[test]
+fn test_reject_too_many_elements() {
+    let json = r#"{"storage":{"size":2,"multiple":2,"elements":[1,2,3]},"warm_up":"Strict"}"#;
+    let res: Result<SlidingWindow<VectorStorage<i32>, i32>, _> = serde_json::from_str(json);
+    assert!(res.is_err());
+
+    let json = r#"{"storage":{"size":4,"capacity":8,"elements":[1,2,3,4,5]},"warm_up":"Strict"}"#;
+    let res: Result<SlidingWindow<ArrayStorage<i32, SIZE, CAPACITY>, i32>, _> = serde_json::from_str(json);
+    assert!(res.is_err());
+}
+
+#[test]
+fn test_reject_inconsistent_configuration() {
+    let json = r#"{"storage":{"size":0,"multiple":2,"elements":[]},"warm_up":"Strict"}"#;
+    assert!(serde_json::from_str::<VectorStorage<i32>>(json).is_err());
+
+    let json = r#"{"storage":{"size":4,"multiple":0,"elements":[]},"warm_up":"Strict"}"#;
+    assert!(serde_json::from_str::<SlidingWindow<VectorStorage<i32>, i32>>(json).is_err());
+
+    // The const generic configuration must match.
+    let json = r#"{"size":4,"capacity":16,"elements":[]}"#;
+    assert!(serde_json::from_str::<ArrayStorage<i32, SIZE, CAPACITY>>(json).is_err());
+    let json = r#"{"size":3,"capacity":8,"elements":[]}"#;
+    assert!(serde_json::from_str::<ArrayStorage<i32, SIZE, CAPACITY>>(json).is_err());
+
+    // A padded window is always filled.
+    let json = r#"{"storage":{"size":4,"multiple":2,"elements":[1]},"warm_up":{"Padded":0}}"#;
+    assert!(serde_json::from_str::<SlidingWindow<VectorStorage<i32>, i32>>(json).is_err());
+}
+
+#[test]
+fn test_reject_invalid_capacity() {
+    // A capacity that cannot be allocated fails instead of aborting.
+    let json = r#"{"storage":{"size":1000000000000000,"multiple":1000,"elements":[]},"warm_up":"Strict"}"#;
+    assert!(serde_json::from_str::<SlidingWindow<VectorStorage<u64>, u64>>(json).is_err());
+
+    // size times multiple overflows
+    let json = format!(r#"{{"storage":{{"size":{},"multiple":8,"elements":[]}},"warm_up":"Strict"}}"#, 1u64 << 62);
+    assert!(serde_json::from_str::<SlidingWindow<VectorStorage<u64>, u64>>(&json).is_err());
+
+    // the multiple must be at least 2, like in the builder
+    let json = r#"{"storage":{"size":4,"multiple":1,"elements":[]},"warm_up":"Strict"}"#;
+    assert!(serde_json::from_str::<SlidingWindow<VectorStorage<i32>, i32>>(json).is_err());
+}
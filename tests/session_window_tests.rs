@@ -0,0 +1,127 @@
+#![allow(clippy::bool_assert_comparison)]
+
+use std::time::Duration;
+
+use sliding_window::session_window::SessionWindow;
+use sliding_window::time_window::ManualClock;
+
+const CAPACITY: usize = 8;
+
+fn secs(s: u64) -> Duration {
+    Duration::from_secs(s)
+}
+
+fn get_session_window() -> SessionWindow<i32, ManualClock> {
+    SessionWindow::with_clock(secs(5), secs(60), CAPACITY, ManualClock::new(secs(100)))
+}
+
+#[test]
+fn test_empty() {
+    let mut window = get_session_window();
+    assert_eq!(window.gap(), secs(5));
+    assert_eq!(window.max_duration(), secs(60));
+    assert_eq!(window.current(), None);
+    assert_eq!(window.last_closed(), None);
+    assert_eq!(window.close(), None);
+    assert_eq!(window.expire(), None);
+}
+
+#[test]
+fn test_gap_closes_session() {
+    let mut window = get_session_window();
+
+    assert_eq!(window.push_at(secs(0), 1), None);
+    assert_eq!(window.push_at(secs(4), 2), None);
+    assert_eq!(window.push_at(secs(8), 3), None);
+
+    let current = window.current().unwrap();
+    assert_eq!(current.len(), 3);
+    assert_eq!(current.start(), secs(0));
+    assert_eq!(current.end(), secs(8));
+
+    // 5 seconds without an element close the session
+    let closed = window.push_at(secs(13), 4).unwrap();
+    assert_eq!(closed.vec(), vec![1, 2, 3]);
+    assert_eq!(closed.start(), secs(0));
+    assert_eq!(closed.end(), secs(8));
+    assert_eq!(closed.duration(), secs(8));
+
+    assert_eq!(window.current().unwrap().vec(), vec![4]);
+    assert_eq!(window.last_closed().unwrap().vec(), vec![1, 2, 3]);
+}
+
+#[test]
+fn test_max_duration_closes_session() {
+    let mut window = SessionWindow::with_clock(secs(5), secs(10), CAPACITY, ManualClock::new(secs(0)));
+
+    let mut sessions = Vec::new();
+    for t in 0..25 {
+        if let Some(closed) = window.push_at(secs(t), t as i32) {
+            sessions.push(closed.vec());
+        }
+    }
+
+    assert_eq!(sessions.len(), 2);
+    assert_eq!(sessions[0], (0..10).collect::<Vec<_>>());
+    assert_eq!(sessions[1], (10..20).collect::<Vec<_>>());
+    assert_eq!(window.current().unwrap().vec(), (20..25).collect::<Vec<_>>());
+}
+
+#[test]
+fn test_close_idle() {
+    let mut window = get_session_window();
+    window.push_at(secs(10), 1);
+    window.push_at(secs(12), 2);
+
+    assert_eq!(window.close_idle(secs(16)), None);
+
+    let closed = window.close_idle(secs(17)).unwrap();
+    assert_eq!(closed.entries(), &[(secs(10), 1), (secs(12), 2)]);
+    assert_eq!(window.current(), None);
+    assert_eq!(window.close_idle(secs(100)), None);
+}
+
+#[test]
+fn test_clock() {
+    let mut window = get_session_window();
+    window.push(1);
+    window.clock().advance(secs(2));
+    window.push(2);
+
+    window.clock().advance(secs(4));
+    assert_eq!(window.expire(), None);
+
+    window.clock().advance(secs(1));
+    let closed = window.expire().unwrap();
+    assert_eq!(closed.entries(), &[(secs(100), 1), (secs(102), 2)]);
+
+    window.clock().advance(secs(60));
+    assert_eq!(window.push(3), None);
+}
+
+#[test]
+fn test_close() {
+    let mut window = get_session_window();
+    window.push_at(secs(0), 1);
+
+    let closed = window.close().unwrap();
+    assert_eq!(closed.values().collect::<Vec<_>>(), vec![1]);
+    assert_eq!(window.current(), None);
+    assert_eq!(window.close(), None);
+}
+
+#[test]
+fn test_many_sessions() {
+    let mut window = get_session_window();
+
+    for session in 0..10 {
+        let start = session * 100;
+        for t in 0..CAPACITY as u64 {
+            window.push_at(secs(start + t), t as i32);
+        }
+    }
+
+    let closed = window.close().unwrap();
+    assert_eq!(closed.len(), CAPACITY);
+    assert_eq!(closed.start(), secs(900));
+}
@@ -0,0 +1,73 @@
+#![allow(clippy::bool_assert_comparison)]
+
+use sliding_window::sliding_iter::SlidingIteratorExt;
+
+const SIZE: usize = 3;
+const MULTIPLE: usize = 2;
+const CAPACITY: usize = 6;
+
+#[test]
+fn test_sliding_vec() {
+    let windows: Vec<Vec<i32>> = (1..=5).sliding(SIZE, MULTIPLE).collect();
+    assert_eq!(windows, vec![vec![1, 2, 3], vec![2, 3, 4], vec![3, 4, 5]]);
+}
+
+#[test]
+fn test_sliding_array() {
+    let windows: Vec<[i32; SIZE]> = (1..=5).sliding_array::<SIZE, CAPACITY>().collect();
+    assert_eq!(windows, vec![[1, 2, 3], [2, 3, 4], [3, 4, 5]]);
+}
+
+#[test]
+fn test_sliding_matches_slice_windows() {
+    let data: Vec<u64> = (0..100).map(|i| i * i % 17).collect();
+
+    let expected: Vec<Vec<u64>> = data.windows(SIZE).map(<[u64]>::to_vec).collect();
+    let vec_windows: Vec<Vec<u64>> = data.iter().copied().sliding(SIZE, MULTIPLE).collect();
+    let arr_windows: Vec<Vec<u64>> = data.iter().copied().sliding_array::<SIZE, CAPACITY>().map(|w| w.to_vec()).collect();
+
+    assert_eq!(vec_windows, expected);
+    assert_eq!(arr_windows, expected);
+}
+
+#[test]
+fn test_next_window() {
+    let mut sliding = (0..10).sliding_array::<SIZE, CAPACITY>();
+
+    let mut count = 0;
+    while let Some(window) = sliding.next_window() {
+        assert_eq!(window.len(), SIZE);
+        assert_eq!(window[0] + 2, window[2]);
+        count += 1;
+    }
+
+    assert_eq!(count, 10 - SIZE + 1);
+    assert_eq!(sliding.next_window(), None);
+    assert_eq!(sliding.window().filled(), true);
+}
+
+#[test]
+fn test_for_each_window() {
+    let mut sums = Vec::new();
+    (1..=5).sliding(SIZE, MULTIPLE).for_each_window(|window| sums.push(window.iter().sum::<i32>()));
+    assert_eq!(sums, vec![6, 9, 12]);
+}
+
+#[test]
+fn test_short_iterator() {
+    assert_eq!((1..SIZE as i32).sliding(SIZE, MULTIPLE).next(), None);
+    assert_eq!((1..SIZE as i32).sliding_array::<SIZE, CAPACITY>().next(), None);
+    assert_eq!((1..1).sliding(SIZE, MULTIPLE).count(), 0);
+}
+
+#[test]
+fn test_size_hint() {
+    let mut sliding = (0..10).sliding(SIZE, MULTIPLE);
+    assert_eq!(sliding.size_hint(), (8, Some(8)));
+
+    sliding.next();
+    assert_eq!(sliding.size_hint(), (7, Some(7)));
+
+    assert_eq!((0..2).sliding_array::<SIZE, CAPACITY>().size_hint(), (0, Some(0)));
+    assert_eq!((0..).sliding(SIZE, MULTIPLE).size_hint(), (usize::MAX - 2, None));
+}
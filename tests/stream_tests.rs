@@ -0,0 +1,91 @@
+#![allow(clippy::bool_assert_comparison)]
+
+use futures::executor::block_on;
+use futures::stream::{self, StreamExt};
+use futures::FutureExt;
+
+use sliding_window::stream::SlidingWindowStreamExt;
+use sliding_window::warm_up::WarmUp;
+
+const SIZE: usize = 3;
+const MULTIPLE: usize = 2;
+
+#[test]
+fn test_sliding_window() {
+    let windows: Vec<Vec<i32>> = block_on(
+        stream::iter(1..=5).sliding_window(SIZE, MULTIPLE).collect()
+    );
+
+    assert_eq!(windows, vec![vec![1, 2, 3], vec![2, 3, 4], vec![3, 4, 5]]);
+}
+
+#[test]
+fn test_sliding_window_rewind() {
+    let windows: Vec<Vec<i32>> = block_on(
+        stream::iter(0..20).sliding_window(SIZE, MULTIPLE).collect()
+    );
+
+    assert_eq!(windows.len(), 20 - SIZE + 1);
+    for (i, window) in windows.iter().enumerate() {
+        let i = i as i32;
+        assert_eq!(window, &vec![i, i + 1, i + 2]);
+    }
+}
+
+#[test]
+fn test_sliding_window_map() {
+    let sums: Vec<i32> = block_on(
+        stream::iter(1..=5).sliding_window_map(SIZE, MULTIPLE, |w: &[i32]| w.iter().sum::<i32>()).collect()
+    );
+
+    assert_eq!(sums, vec![6, 9, 12]);
+}
+
+#[test]
+fn test_sliding_window_partial() {
+    let windows: Vec<Vec<i32>> = block_on(
+        stream::iter(1..=4)
+            .sliding_window(SIZE, MULTIPLE)
+            .with_warm_up(WarmUp::Partial)
+            .collect()
+    );
+
+    assert_eq!(windows, vec![vec![1], vec![1, 2], vec![1, 2, 3], vec![2, 3, 4]]);
+}
+
+#[test]
+fn test_sliding_window_padded() {
+    let windows: Vec<Vec<i32>> = block_on(
+        stream::iter(1..=2)
+            .sliding_window(SIZE, MULTIPLE)
+            .with_warm_up(WarmUp::Padded(0))
+            .collect()
+    );
+
+    assert_eq!(windows, vec![vec![0, 0, 1], vec![0, 1, 2]]);
+}
+
+#[test]
+fn test_sliding_window_short_stream() {
+    let mut windows = stream::iter(1..SIZE as i32).sliding_window(SIZE, MULTIPLE);
+
+    assert_eq!(block_on(windows.next()), None);
+    assert_eq!(windows.window().len(), SIZE - 1);
+    assert_eq!(windows.window().filled(), false);
+}
+
+#[test]
+fn test_sliding_window_pending() {
+    // Items arrive through a channel, so the adapter has to handle pending polls.
+    let (sender, receiver) = futures::channel::mpsc::unbounded();
+    let mut windows = receiver.sliding_window(2, MULTIPLE);
+
+    sender.unbounded_send(1.0).unwrap();
+    assert_eq!(windows.next().now_or_never(), None);
+
+    sender.unbounded_send(2.0).unwrap();
+    assert_eq!(block_on(windows.next()), Some(vec![1.0, 2.0]));
+
+    drop(sender);
+    assert_eq!(block_on(windows.next()), None);
+}
@@ -0,0 +1,124 @@
+#![allow(clippy::bool_assert_comparison)]
+
+use std::time::Duration;
+
+use sliding_window::error::SlidingWindowError;
+use sliding_window::time_window::{ManualClock, TimeWindow};
+
+const CAPACITY: usize = 8;
+
+#[derive(Default, Debug, Copy, Clone, Hash, Eq, PartialEq)]
+pub struct Data {
+    dats: i32,
+}
+
+fn secs(s: u64) -> Duration {
+    Duration::from_secs(s)
+}
+
+fn get_time_window() -> TimeWindow<Data, ManualClock> {
+    TimeWindow::with_clock(secs(5), CAPACITY, ManualClock::new(secs(100)))
+}
+
+#[test]
+fn test_empty() {
+    let mut window = get_time_window();
+    assert_eq!(window.empty(), true);
+    assert_eq!(window.first(), Err(SlidingWindowError::Empty));
+    assert_eq!(window.last(), Err(SlidingWindowError::Empty));
+    assert_eq!(window.slice().unwrap_err(), SlidingWindowError::Empty);
+
+    window.push(Data { dats: 0 });
+    assert_eq!(window.empty(), false);
+    assert_eq!(window.len(), 1);
+}
+
+#[test]
+fn test_first_last() {
+    let mut window = get_time_window();
+
+    window.push(Data { dats: 1 });
+    window.clock().advance(secs(1));
+    window.push(Data { dats: 2 });
+
+    assert_eq!(window.first().unwrap().dats, 1);
+    assert_eq!(window.last().unwrap().dats, 2);
+    assert_eq!(window.first_entry().unwrap().0, secs(100));
+    assert_eq!(window.last_entry().unwrap().0, secs(101));
+}
+
+#[test]
+fn test_evict_on_push() {
+    let mut window = get_time_window();
+
+    for i in 0..5 {
+        window.push(Data { dats: i });
+        window.clock().advance(secs(1));
+    }
+    assert_eq!(window.len(), 5);
+
+    // The element pushed at t=100 is exactly 5 seconds old and drops out.
+    window.push(Data { dats: 5 });
+    assert_eq!(window.len(), 5);
+    assert_eq!(window.first().unwrap().dats, 1);
+    assert_eq!(window.last().unwrap().dats, 5);
+
+    // A gap longer than the duration empties the window except the new element.
+    window.clock().advance(secs(60));
+    window.push(Data { dats: 6 });
+    assert_eq!(window.len(), 1);
+    assert_eq!(window.vec().unwrap(), vec![Data { dats: 6 }]);
+}
+
+#[test]
+fn test_expire() {
+    let mut window = get_time_window();
+    window.push(Data { dats: 0 });
+
+    window.clock().advance(secs(4));
+    window.expire();
+    assert_eq!(window.len(), 1);
+
+    window.clock().advance(secs(1));
+    window.expire();
+    assert_eq!(window.empty(), true);
+}
+
+#[test]
+fn test_rewind() {
+    let mut window = get_time_window();
+
+    // Push many more elements than the capacity to force several rewinds.
+    for i in 0..100 {
+        window.push_at(secs(i), Data { dats: i as i32 });
+
+        let slice = window.slice().expect("Failed to get slice");
+        let expected: Vec<i32> = (i.saturating_sub(4)..=i).map(|x| x as i32).collect();
+        let actual: Vec<i32> = slice.iter().map(|(_, d)| d.dats).collect();
+        assert_eq!(actual, expected);
+    }
+}
+
+#[test]
+fn test_unbounded_duration() {
+    let mut window = TimeWindow::with_clock(Duration::MAX, CAPACITY, ManualClock::new(secs(100)));
+
+    window.push_at(secs(1), Data { dats: 1 });
+    window.push_at(Duration::MAX, Data { dats: 2 });
+    window.evict(Duration::MAX);
+
+    assert_eq!(window.len(), 2);
+    assert_eq!(window.first(), Ok(Data { dats: 1 }));
+}
+
+#[test]
+fn test_rewind_headroom() {
+    // the window holds 7 of 8 elements, so without headroom every push would rewind
+    let mut window = TimeWindow::with_clock(secs(7), CAPACITY, ManualClock::new(secs(100)));
+
+    for i in 0..100 {
+        window.push_at(secs(i), Data { dats: i as i32 });
+        assert_eq!(window.len(), (i as usize + 1).min(7));
+    }
+    assert_eq!(window.capacity() >= 2 * 7, true);
+}
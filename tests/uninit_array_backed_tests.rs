@@ -0,0 +1,85 @@
+#![allow(clippy::bool_assert_comparison)]
+
+use std::rc::Rc;
+
+use sliding_window::error::SlidingWindowError;
+use sliding_window::sliding_window::{new_with_uninit_array_storage, SlidingWindow};
+use sliding_window::storage_array_uninit::UninitArrayStorage;
+
+const SIZE: usize = 4;
+const CAPACITY: usize = 8;
+
+/// Deliberately implements neither `Default` nor `Copy`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Data {
+    dats: i32,
+}
+
+fn get_sliding_window() -> SlidingWindow<UninitArrayStorage<Data, SIZE, CAPACITY>, Data> {
+    new_with_uninit_array_storage()
+}
+
+#[test]
+fn test_empty() {
+    let mut window = get_sliding_window();
+    assert_eq!(window.empty(), true);
+    assert_eq!(window.first(), Err(SlidingWindowError::Empty));
+
+    window.push(Data { dats: 0 });
+    assert_eq!(window.size(), SIZE);
+    assert_eq!(window.empty(), false);
+    assert_eq!(window.filled(), false);
+}
+
+#[test]
+fn test_last() {
+    let mut window = get_sliding_window();
+    assert_eq!(window.last(), Err(SlidingWindowError::NotFilled { len: 0, size: SIZE }));
+
+    for i in 0..SIZE as i32 {
+        window.push(Data { dats: i });
+    }
+    assert_eq!(window.filled(), true);
+    assert_eq!(window.first(), Ok(Data { dats: 0 }));
+    assert_eq!(window.last(), Ok(Data { dats: 3 }));
+}
+
+#[test]
+fn test_rewind() {
+    let mut window = get_sliding_window();
+
+    // Push well past the capacity of 8 to run through several rewinds.
+    for i in 0..40 {
+        let evicted = window.push_evict(Data { dats: i });
+        assert_eq!(evicted.map(|d| d.dats), if i >= SIZE as i32 { Some(i - SIZE as i32) } else { None });
+
+        let first = (i - SIZE as i32 + 1).max(0);
+        let dats: Vec<i32> = window.iter().map(|d| d.dats).collect();
+        assert_eq!(dats, (first..=i).collect::<Vec<i32>>());
+    }
+
+    let arr: [Data; SIZE] = window.arr().expect("Failed to get arr");
+    assert_eq!(arr[0].dats, 36);
+    assert_eq!(arr[3].dats, 39);
+}
+
+#[test]
+fn test_drop_accounting() {
+    let tracker = Rc::new(());
+    {
+        let mut window = new_with_uninit_array_storage::<Rc<()>, SIZE, CAPACITY>();
+        for _ in 0..25 {
+            window.push(Rc::clone(&tracker));
+            assert!(Rc::strong_count(&tracker) <= SIZE + 1);
+        }
+        assert_eq!(Rc::strong_count(&tracker), SIZE + 1);
+    }
+    assert_eq!(Rc::strong_count(&tracker), 1);
+
+    {
+        let mut window = new_with_uninit_array_storage::<Rc<()>, SIZE, CAPACITY>();
+        window.push(Rc::clone(&tracker));
+        assert_eq!(Rc::strong_count(&tracker), 2);
+    }
+    assert_eq!(Rc::strong_count(&tracker), 1);
+}
@@ -1,3 +1,6 @@
+#![allow(clippy::bool_assert_comparison, clippy::get_first)]
+
+use sliding_window::error::SlidingWindowError;
 use sliding_window::sliding_window::{new_with_vector_storage, SlidingWindow};
 use sliding_window::storage_vec::VectorStorage;
 
@@ -203,4 +206,170 @@ fn test_vec() {
     let e2 = window.last().unwrap();
     let v2 = v.get(SIZE - 1).unwrap();
     assert_eq!(e2.dats, v2.dats);
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_errors() {
+    let mut window = get_sliding_window();
+    assert_eq!(window.first(), Err(SlidingWindowError::Empty));
+    assert_eq!(window.last(), Err(SlidingWindowError::NotFilled { len: 0, size: SIZE }));
+
+    let d = Data { dats: 0 };
+    window.push(d);
+    window.push(d);
+    assert_eq!(window.vec().unwrap_err(), SlidingWindowError::NotFilled { len: 2, size: SIZE });
+
+    window.push(d);
+    window.push(d);
+    assert_eq!(window.filled(), true);
+
+    let res = window.arr::<8>();
+    assert_eq!(res.unwrap_err(), SlidingWindowError::SizeMismatch { expected: SIZE, got: 8 });
+}
+
+#[test]
+fn test_rewind() {
+    let mut window = get_sliding_window();
+
+    // Push well past the capacity of 8 to run through several rewinds.
+    for i in 0..40 {
+        window.push(Data { dats: i });
+
+        let first = (i - SIZE as i32 + 1).max(0);
+        assert_eq!(window.first().unwrap().dats, first);
+
+        if window.filled() {
+            let slice = window.slice().expect("Failed to get slice");
+            let dats: Vec<i32> = slice.iter().map(|d| d.dats).collect();
+            assert_eq!(dats, (first..=i).collect::<Vec<i32>>());
+            assert_eq!(window.last().unwrap().dats, i);
+        }
+    }
+}
+
+fn dats(window: &SlidingWindow<VectorStorage<Data>, Data>) -> Vec<i32> {
+    window.iter().map(|d| d.dats).collect()
+}
+
+#[test]
+fn test_resize_grow() {
+    let mut window = get_sliding_window();
+    for i in 0..6 {
+        window.push(Data { dats: i });
+    }
+    assert_eq!(dats(&window), vec![2, 3, 4, 5]);
+
+    // the evicted elements 0 and 1 are still in the vector
+    window.resize(6).unwrap();
+    assert_eq!(window.size(), 6);
+    assert_eq!(window.filled(), true);
+    assert_eq!(dats(&window), vec![0, 1, 2, 3, 4, 5]);
+
+    // more history than retained
+    window.resize(10).unwrap();
+    assert_eq!(window.filled(), false);
+    assert_eq!(window.len(), 6);
+
+    for i in 6..10 {
+        window.push(Data { dats: i });
+    }
+    assert_eq!(dats(&window), (0..10).collect::<Vec<i32>>());
+}
+
+#[test]
+fn test_resize_shrink() {
+    let mut window = get_sliding_window();
+    for i in 0..4 {
+        window.push(Data { dats: i });
+    }
+
+    window.resize(2).unwrap();
+    assert_eq!(window.size(), 2);
+    assert_eq!(dats(&window), vec![2, 3]);
+    assert_eq!(window.first().unwrap().dats, 2);
+    assert_eq!(window.last().unwrap().dats, 3);
+
+    window.push(Data { dats: 4 });
+    assert_eq!(dats(&window), vec![3, 4]);
+}
+
+#[test]
+fn test_resize_rewind() {
+    let mut window = get_sliding_window();
+
+    // Resize repeatedly while pushing through many rewinds.
+    let sizes = [4, 7, 1, 3, 12, 5, 2, 9];
+    let mut history: Vec<i32> = Vec::new();
+    for i in 0..400 {
+        if i % 50 == 0 {
+            window.resize(sizes[(i / 50) as usize]).unwrap();
+        }
+
+        window.push(Data { dats: i });
+        history.push(i);
+
+        // The window always holds the newest elements, and is filled once enough were pushed since the resize.
+        let len = window.len();
+        assert_eq!(dats(&window), history[history.len() - len..]);
+        assert_eq!(len <= window.size(), true);
+        if i % 50 >= window.size() as i32 {
+            assert_eq!(len, window.size());
+        }
+    }
+}
+
+#[test]
+fn test_set_multiple() {
+    let mut window = get_sliding_window();
+    assert_eq!(window.multiple(), MULT);
+
+    for i in 0..6 {
+        window.push(Data { dats: i });
+    }
+
+    window.set_multiple(100).unwrap();
+    assert_eq!(window.multiple(), 100);
+    assert_eq!(dats(&window), vec![2, 3, 4, 5]);
+
+    window.set_multiple(2).unwrap();
+    for i in 6..30 {
+        window.push(Data { dats: i });
+        assert_eq!(dats(&window), ((i - 3)..=i).collect::<Vec<i32>>());
+    }
+}
+
+#[test]
+fn test_resize_padded() {
+    use sliding_window::sliding_window::new_with_vector_storage_and_warm_up;
+    use sliding_window::warm_up::WarmUp;
+
+    let mut window = new_with_vector_storage_and_warm_up(2, MULT, WarmUp::Padded(0));
+    window.push(1);
+    window.push(2);
+
+    window.resize(4).unwrap();
+    assert_eq!(window.slice(), Ok(&[0, 0, 1, 2][..]));
+    assert_eq!(window.last(), Ok(2));
+}
+
+#[test]
+fn test_resize_invalid() {
+    let mut window = get_sliding_window();
+    for i in 0..6 {
+        window.push(Data { dats: i });
+    }
+
+    assert_eq!(window.resize(0), Err(SlidingWindowError::InvalidSize));
+    assert_eq!(window.resize(usize::MAX), Err(SlidingWindowError::InvalidMultiple { multiple: MULT }));
+
+    // a multiple below 2 would rewind on every push
+    for multiple in [0, 1] {
+        assert_eq!(window.set_multiple(multiple), Err(SlidingWindowError::InvalidMultiple { multiple }));
+    }
+    assert_eq!(window.set_multiple(usize::MAX), Err(SlidingWindowError::InvalidMultiple { multiple: usize::MAX }));
+
+    // the window is left unchanged
+    assert_eq!(window.size(), SIZE);
+    assert_eq!(window.multiple(), MULT);
+    assert_eq!(dats(&window), vec![2, 3, 4, 5]);
+}
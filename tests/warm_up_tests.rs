@@ -0,0 +1,91 @@
+#![allow(clippy::bool_assert_comparison)]
+
+use sliding_window::error::SlidingWindowError;
+use sliding_window::sliding_window::{new_with_array_storage_and_warm_up, new_with_vector_storage, new_with_vector_storage_and_warm_up, SlidingWindow};
+use sliding_window::storage::Storage;
+use sliding_window::warm_up::WarmUp;
+
+const SIZE: usize = 4;
+const MULT: usize = 2;
+const CAPACITY: usize = 8;
+
+fn check_strict<S: Storage<i32>>(window: &mut SlidingWindow<S, i32>) {
+    assert_eq!(window.warm_up(), &WarmUp::Strict);
+    window.push(1);
+    window.push(2);
+    assert_eq!(window.first(), Ok(1));
+    assert_eq!(window.last(), Err(SlidingWindowError::NotFilled { len: 2, size: SIZE }));
+    assert_eq!(window.slice().unwrap_err(), SlidingWindowError::NotFilled { len: 2, size: SIZE });
+    assert_eq!(window.vec().unwrap_err(), SlidingWindowError::NotFilled { len: 2, size: SIZE });
+}
+
+fn check_partial<S: Storage<i32>>(window: &mut SlidingWindow<S, i32>) {
+    assert_eq!(window.last(), Err(SlidingWindowError::Empty));
+    assert_eq!(window.slice().unwrap(), &[] as &[i32]);
+
+    window.push(1);
+    window.push(2);
+    assert_eq!(window.filled(), false);
+    assert_eq!(window.len(), 2);
+    assert_eq!(window.first(), Ok(1));
+    assert_eq!(window.last(), Ok(2));
+    assert_eq!(window.slice().unwrap(), &[1, 2]);
+    assert_eq!(window.vec().unwrap(), vec![1, 2]);
+    assert_eq!(window.arr::<2>().unwrap(), [1, 2]);
+    assert_eq!(window.arr::<SIZE>().unwrap_err(), SlidingWindowError::SizeMismatch { expected: 2, got: SIZE });
+
+    window.push(3);
+    window.push(4);
+    window.push(5);
+    assert_eq!(window.filled(), true);
+    assert_eq!(window.slice().unwrap(), &[2, 3, 4, 5]);
+    assert_eq!(window.arr::<SIZE>().unwrap(), [2, 3, 4, 5]);
+}
+
+fn check_padded<S: Storage<i32>>(window: &mut SlidingWindow<S, i32>) {
+    assert_eq!(window.filled(), true);
+    assert_eq!(window.len(), SIZE);
+    assert_eq!(window.slice().unwrap(), &[7, 7, 7, 7]);
+
+    window.push(1);
+    window.push(2);
+    assert_eq!(window.first(), Ok(7));
+    assert_eq!(window.last(), Ok(2));
+    assert_eq!(window.vec().unwrap(), vec![7, 7, 1, 2]);
+}
+
+#[test]
+fn test_strict() {
+    check_strict(&mut new_with_vector_storage_and_warm_up(SIZE, MULT, WarmUp::Strict));
+    check_strict(&mut new_with_array_storage_and_warm_up::<i32, SIZE, CAPACITY>(WarmUp::Strict));
+}
+
+#[test]
+fn test_partial() {
+    check_partial(&mut new_with_vector_storage_and_warm_up(SIZE, MULT, WarmUp::Partial));
+    check_partial(&mut new_with_array_storage_and_warm_up::<i32, SIZE, CAPACITY>(WarmUp::Partial));
+}
+
+#[test]
+fn test_padded() {
+    check_padded(&mut new_with_vector_storage_and_warm_up(SIZE, MULT, WarmUp::Padded(7)));
+    check_padded(&mut new_with_array_storage_and_warm_up::<i32, SIZE, CAPACITY>(WarmUp::Padded(7)));
+}
+
+#[test]
+fn test_padded_default() {
+    let window = new_with_vector_storage_and_warm_up(SIZE, MULT, WarmUp::Padded(i32::default()));
+    assert_eq!(window.vec().unwrap(), vec![0; SIZE]);
+}
+
+#[test]
+fn test_prefill_from() {
+    let mut window = new_with_vector_storage(SIZE, MULT);
+    window.prefill_from(&[1, 2, 3, 4, 5, 6, 7, 8, 9, 10]);
+    assert_eq!(window.filled(), true);
+    assert_eq!(window.vec().unwrap(), vec![7, 8, 9, 10]);
+
+    let mut window = new_with_array_storage_and_warm_up::<i32, SIZE, CAPACITY>(WarmUp::Partial);
+    window.prefill_from(&[1, 2]);
+    assert_eq!(window.vec().unwrap(), vec![1, 2]);
+}